@@ -0,0 +1,66 @@
+use std::{error::Error, fmt};
+
+// Errors shared by the univariate and multilinear polynomials
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolynomialError {
+    // A polynomial needs at least one coefficient, interpolation at least one point
+    EmptyInput,
+    // The result does not fit the representation, e.g. a product that is not multilinear
+    DegreeError,
+    // The domain size is not supported by the field or the offset is zero
+    DomainError,
+    // The divisor is the zero polynomial
+    DivisionByZero,
+    // The polynomial shares a factor with the modulus
+    NotInvertible,
+    // The field characteristic divides an exponent, e.g. when integrating x^(p - 1)
+    CharacteristicDividesExponent { exponent: usize },
+    // Two interpolation points share the same x-coordinate
+    DuplicatePoints,
+    // The operands or inputs do not agree on the number of variables
+    VariableCountMismatch { expected: usize, found: usize },
+    // A variable or term index is not below its bound
+    IndexOutOfRange { index: usize, bound: usize },
+    // Two inputs that must have the same length do not
+    LengthMismatch { expected: usize, found: usize },
+    // Pointwise operands are evaluations over different domains
+    DomainMismatch,
+    // A size that must be a power of two, e.g. a blowup factor
+    NotPowerOfTwo(usize),
+    // A division that must be exact leaves a non-zero remainder,
+    // e.g. a constraint that does not vanish on the whole domain
+    NonExactDivision,
+    // The string is not a polynomial in x, with the reason
+    ParseError(String),
+}
+
+impl fmt::Display for PolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolynomialError::EmptyInput => write!(f, "the input must not be empty"),
+            PolynomialError::DegreeError => write!(f, "the degree does not fit the polynomial representation"),
+            PolynomialError::DomainError => write!(f, "the evaluation domain is not supported by the field"),
+            PolynomialError::DivisionByZero => write!(f, "division by zero"),
+            PolynomialError::NotInvertible => write!(f, "the polynomial is not invertible modulo the given modulus"),
+            PolynomialError::DuplicatePoints => write!(f, "interpolation points must have distinct x-coordinates"),
+            PolynomialError::VariableCountMismatch { expected, found } => {
+                write!(f, "expected {} variables, found {}", expected, found)
+            }
+            PolynomialError::IndexOutOfRange { index, bound } => {
+                write!(f, "index {} is out of range, it must be below {}", index, bound)
+            }
+            PolynomialError::CharacteristicDividesExponent { exponent } => {
+                write!(f, "the field characteristic divides the exponent {}", exponent)
+            }
+            PolynomialError::LengthMismatch { expected, found } => {
+                write!(f, "expected {} values, found {}", expected, found)
+            }
+            PolynomialError::DomainMismatch => write!(f, "the evaluations are over different domains"),
+            PolynomialError::NotPowerOfTwo(size) => write!(f, "{} is not a power of two", size),
+            PolynomialError::NonExactDivision => write!(f, "the division leaves a non-zero remainder"),
+            PolynomialError::ParseError(reason) => write!(f, "cannot parse the polynomial: {}", reason),
+        }
+    }
+}
+
+impl Error for PolynomialError {}
@@ -1,2 +1,7 @@
+#[macro_use]
+mod macros;
+pub mod error;
 pub mod multilinear;
-pub mod univariate;
\ No newline at end of file
+pub mod sumcheck;
+pub mod univariate;
+mod serialization;
@@ -0,0 +1,28 @@
+// Derives the owned and mixed operand impls from the `&a op &b` impl
+macro_rules! forward_binop {
+    ($lhs:ident, $rhs:ident, $output:ident, $trait:ident, $method:ident) => {
+        impl<F: PrimeField> $trait<$rhs<F>> for $lhs<F> {
+            type Output = $output<F>;
+
+            fn $method(self, other: $rhs<F>) -> $output<F> {
+                (&self).$method(&other)
+            }
+        }
+
+        impl<F: PrimeField> $trait<&$rhs<F>> for $lhs<F> {
+            type Output = $output<F>;
+
+            fn $method(self, other: &$rhs<F>) -> $output<F> {
+                (&self).$method(other)
+            }
+        }
+
+        impl<F: PrimeField> $trait<$rhs<F>> for &$lhs<F> {
+            type Output = $output<F>;
+
+            fn $method(self, other: $rhs<F>) -> $output<F> {
+                self.$method(&other)
+            }
+        }
+    };
+}
@@ -1,18 +1,19 @@
 use std::ops::{Add, Mul};
 
-use ark_bn254::Fq;
-use ark_ff::{BigInteger, PrimeField};
+use ark_ff::PrimeField;
 
-use super::MultiLinearPolynomial;
+use super::{MultiLinearPolynomial, PolynomialError};
 
 
-impl<F: PrimeField> Add for &MultiLinearPolynomial<F> {
-    type Output = MultiLinearPolynomial<F>;
-
-    fn add(self, other: &MultiLinearPolynomial<F>) -> MultiLinearPolynomial<F> {
+impl<F: PrimeField> MultiLinearPolynomial<F> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(lhs = self.coefficients().len(), rhs = other.coefficients().len())))]
+    pub fn try_add(&self, other: &MultiLinearPolynomial<F>) -> Result<MultiLinearPolynomial<F>, PolynomialError> {
         // Check if the two polynomials have the same number of variables
         if self.variables != other.variables {
-            panic!("The two polynomials must have the same number of variables");
+            return Err(PolynomialError::VariableCountMismatch {
+                expected: self.variables,
+                found: other.variables,
+            });
         }
 
         let (mut larger, mut smaller) = if self.coefficients().len() > other.coefficients().len() {
@@ -30,15 +31,15 @@ impl<F: PrimeField> Add for &MultiLinearPolynomial<F> {
                 .iter()
                 .position(|(pos, _)| pos == variables_a);
 
-            if existing_coefficient.is_some() {
+            if let Some(position) = existing_coefficient {
                 summed_coefficients.push((
                     *variables_a, 
-                    *coefficient_a + smaller[existing_coefficient.unwrap()].1
+                    *coefficient_a + smaller[position].1
                 ));
 
                 // convert the coefficients to 0 here
                 *coefficient_a = F::zero();
-                smaller[existing_coefficient.unwrap()].1 = F::zero();
+                smaller[position].1 = F::zero();
                 continue;
             }  
 
@@ -60,57 +61,69 @@ impl<F: PrimeField> Add for &MultiLinearPolynomial<F> {
             }
         }
         
-        MultiLinearPolynomial::new(
+        MultiLinearPolynomial::try_new(
             self.variables,
             summed_coefficients
         )
     }
+
+    // The product stays multilinear only when no variable appears in both operands
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(lhs = self.coefficients().len(), rhs = other.coefficients().len())))]
+    pub fn try_mul(&self, other: &MultiLinearPolynomial<F>) -> Result<MultiLinearPolynomial<F>, PolynomialError> {
+        if self.variables != other.variables {
+            return Err(PolynomialError::VariableCountMismatch {
+                expected: self.variables,
+                found: other.variables,
+            });
+        }
+        let (larger, smaller) = if self.coefficients().len() > other.coefficients().len() {
+            (self.coefficients().clone(), other.coefficients().clone())
+        } else {
+            (other.coefficients().clone(), self.coefficients().clone())
+        };
+
+        let mut product_result = vec![];
+
+        // For each index in the larger polynomial, multiply it with the smaller polynomial
+        // Add the variable of each to get the new position
+        for (variables_a, coefficient_a) in larger.iter() {
+            for (variables_b, coefficient_b) in smaller.iter() {
+                if variables_a & variables_b != 0 {
+                    return Err(PolynomialError::DegreeError);
+                }
+                product_result.push((
+                    variables_a + variables_b, 
+                    *coefficient_a * *coefficient_b
+                ))
+            }
+        }
+
+        MultiLinearPolynomial::try_new(
+            self.variables,
+            product_result
+        )
+    }
+}
+
+impl<F: PrimeField> Add for &MultiLinearPolynomial<F> {
+    type Output = MultiLinearPolynomial<F>;
+
+    fn add(self, other: &MultiLinearPolynomial<F>) -> MultiLinearPolynomial<F> {
+        self.try_add(other).expect("The two polynomials must have the same number of variables")
+    }
 }
 
 impl<F: PrimeField> Mul for &MultiLinearPolynomial<F> {
- type Output = MultiLinearPolynomial<F>;
-
- fn mul(self, other: &MultiLinearPolynomial<F>) -> MultiLinearPolynomial<F> {
-    assert_eq!(
-        self.variables, 
-        other.variables, 
-        "The two polynomials must have the same number of variables"
-    );
-    let (larger, smaller) = if self.coefficients().len() > other.coefficients().len() {
-        (self.coefficients().clone(), other.coefficients().clone())
-    } else {
-        (other.coefficients().clone(), self.coefficients().clone())
-    };
-
-    let mut product_result = vec![];
-
-    // For each index in the larger polynomial, multiply it with the smaller polynomial
-    // Add the variable of each to get the new position
-    for (variables_a, coefficient_a) in larger.iter() {
-        for (variables_b, coefficient_b) in smaller.iter() {
-            product_result.push((
-                variables_a + variables_b, 
-                *coefficient_a * *coefficient_b
-            ))
-        }                                                                                                                                                                                                                                                                                                                                                 
+    type Output = MultiLinearPolynomial<F>;
+
+    fn mul(self, other: &MultiLinearPolynomial<F>) -> MultiLinearPolynomial<F> {
+        self.try_mul(other).expect("The two polynomials must share the number of variables but no variable")
     }
-    let total_variables = if self.variables > other.variables {
-        self.variables
-    } else {
-        other.variables
-    };
-
-    MultiLinearPolynomial::new(
-        total_variables,
-        product_result
-    )
- }
 }
 
 #[cfg(test)]
 mod tests {
     use ark_bn254::Fq;
-    use num_bigint::BigInt;
 
     use super::*;
 
@@ -177,4 +190,16 @@ mod tests {
         assert_eq!(poly_product.coefficients()[0].0, 0);
         assert_eq!(poly_product.coefficients()[2].1, Fq::from(-1));
     }
+
+    #[test]
+    fn should_reject_invalid_operands() {
+        let poly_a = MultiLinearPolynomial::new(2, vec![(0, Fq::from(1)), (2, Fq::from(1))]);
+        let poly_b = MultiLinearPolynomial::new(3, vec![(1, Fq::from(1))]);
+        let mismatch = PolynomialError::VariableCountMismatch { expected: 2, found: 3 };
+        assert_eq!(poly_a.try_add(&poly_b).unwrap_err(), mismatch);
+        assert_eq!(poly_a.try_mul(&poly_b).unwrap_err(), mismatch);
+
+        // (1 + a)(1 + a) has the term a^2
+        assert_eq!(poly_a.try_mul(&poly_a).unwrap_err(), PolynomialError::DegreeError);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,305 @@
+// Evaluation form of a multilinear polynomial: its 2^n values over the boolean hypercube {0,1}^n.
+// The point (x_0, ..., x_(n-1)) is stored at the index whose binary form is x_0 x_1 ... x_(n-1),
+// so variable 0 is the most significant bit, the same order as the term indices of MultiLinearPolynomial.
+//
+// Fixing a variable to r halves the table: f(.., r, ..) = (1 - r) f(.., 0, ..) + r f(.., 1, ..),
+// so evaluating at any point takes 2^n - 1 such steps.
+
+use std::ops::{Add, Neg, Sub};
+
+use ark_ff::PrimeField;
+
+use super::{MultiLinearPolynomial, PolynomialError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DenseMultilinear<F: PrimeField> {
+    variables: usize,
+    // evaluations[i] = f(bits of i), always 2^variables entries
+    evaluations: Vec<F>,
+}
+
+impl<F: PrimeField> DenseMultilinear<F> {
+    // The number of evaluations must be a power of two
+    pub fn new(evaluations: Vec<F>) -> Result<Self, PolynomialError> {
+        if evaluations.is_empty() {
+            return Err(PolynomialError::EmptyInput);
+        }
+        if !evaluations.len().is_power_of_two() {
+            return Err(PolynomialError::LengthMismatch {
+                expected: evaluations.len().next_power_of_two(),
+                found: evaluations.len(),
+            });
+        }
+
+        Ok(DenseMultilinear {
+            variables: evaluations.len().trailing_zeros() as usize,
+            evaluations,
+        })
+    }
+
+    pub fn zero(variables: usize) -> Self {
+        Self::try_zero(variables).expect("The table of 2^variables entries must fit in memory")
+    }
+
+    // Fails when 2^variables does not fit in a usize
+    pub fn try_zero(variables: usize) -> Result<Self, PolynomialError> {
+        let size = u32::try_from(variables)
+            .ok()
+            .and_then(|variables| 1usize.checked_shl(variables))
+            .ok_or(PolynomialError::DegreeError)?;
+        Ok(DenseMultilinear {
+            variables,
+            evaluations: vec![F::zero(); size],
+        })
+    }
+
+    pub fn variables(&self) -> usize {
+        self.variables
+    }
+
+    pub fn evaluations(&self) -> &Vec<F> {
+        &self.evaluations
+    }
+
+    // Sum of f over {0,1}^n
+    pub fn sum_over_hypercube(&self) -> F {
+        self.evaluations.iter().sum()
+    }
+
+    pub fn scalar_mul(&self, scalar: F) -> Self {
+        DenseMultilinear {
+            variables: self.variables,
+            evaluations: self.evaluations.iter().map(|value| *value * scalar).collect(),
+        }
+    }
+
+    // f(point) in O(2^n) by fixing the variables one by one
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(variables = self.variables)))]
+    pub fn evaluate(&self, point: &[F]) -> Result<F, PolynomialError> {
+        if point.len() != self.variables {
+            return Err(PolynomialError::VariableCountMismatch {
+                expected: self.variables,
+                found: point.len(),
+            });
+        }
+
+        Ok(self.fix_variables(point)?.evaluations[0])
+    }
+
+    // Fixes the variable at `index` to `value`, leaving a polynomial in the other n - 1 variables
+    pub fn fix_variable(&self, index: usize, value: F) -> Result<Self, PolynomialError> {
+        if index >= self.variables {
+            return Err(PolynomialError::IndexOutOfRange {
+                index,
+                bound: self.variables,
+            });
+        }
+
+        // Splits every index around the bit of the variable
+        let bit = self.variables - 1 - index;
+        let low_mask = (1 << bit) - 1;
+        let evaluations = (0..self.evaluations.len() / 2)
+            .map(|i| {
+                let zero = ((i & !low_mask) << 1) | (i & low_mask);
+                let low = self.evaluations[zero];
+                let high = self.evaluations[zero | (1 << bit)];
+                low + value * (high - low)
+            })
+            .collect();
+
+        Ok(DenseMultilinear {
+            variables: self.variables - 1,
+            evaluations,
+        })
+    }
+
+    // Fixes the first values.len() variables, in order
+    pub fn fix_variables(&self, values: &[F]) -> Result<Self, PolynomialError> {
+        if values.len() > self.variables {
+            return Err(PolynomialError::VariableCountMismatch {
+                expected: self.variables,
+                found: values.len(),
+            });
+        }
+
+        let mut evaluations = self.evaluations.clone();
+        for value in values {
+            // The first variable splits the table into halves
+            let half = evaluations.len() / 2;
+            for i in 0..half {
+                evaluations[i] = evaluations[i] + *value * (evaluations[i + half] - evaluations[i]);
+            }
+            evaluations.truncate(half);
+        }
+
+        Ok(DenseMultilinear {
+            variables: self.variables - values.len(),
+            evaluations,
+        })
+    }
+
+    pub fn try_add(&self, other: &DenseMultilinear<F>) -> Result<DenseMultilinear<F>, PolynomialError> {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    pub fn try_sub(&self, other: &DenseMultilinear<F>) -> Result<DenseMultilinear<F>, PolynomialError> {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    // Pointwise operation, both sides must have the same variables
+    fn zip_with(&self, other: &DenseMultilinear<F>, op: impl Fn(F, F) -> F) -> Result<DenseMultilinear<F>, PolynomialError> {
+        if self.variables != other.variables {
+            return Err(PolynomialError::VariableCountMismatch {
+                expected: self.variables,
+                found: other.variables,
+            });
+        }
+
+        let evaluations = self
+            .evaluations
+            .iter()
+            .zip(other.evaluations.iter())
+            .map(|(a, b)| op(*a, *b))
+            .collect();
+        Ok(DenseMultilinear {
+            variables: self.variables,
+            evaluations,
+        })
+    }
+}
+
+impl<F: PrimeField> From<&MultiLinearPolynomial<F>> for DenseMultilinear<F> {
+    fn from(poly: &MultiLinearPolynomial<F>) -> Self {
+        DenseMultilinear {
+            variables: poly.variables(),
+            evaluations: poly.to_evaluations(),
+        }
+    }
+}
+
+impl<F: PrimeField> From<&DenseMultilinear<F>> for MultiLinearPolynomial<F> {
+    fn from(dense: &DenseMultilinear<F>) -> Self {
+        MultiLinearPolynomial::from_evaluations(dense.evaluations.clone()).expect("The table size is a power of two")
+    }
+}
+
+impl<F: PrimeField> Add for &DenseMultilinear<F> {
+    type Output = DenseMultilinear<F>;
+
+    fn add(self, other: &DenseMultilinear<F>) -> DenseMultilinear<F> {
+        self.try_add(other).expect("The two polynomials must have the same number of variables")
+    }
+}
+
+impl<F: PrimeField> Sub for &DenseMultilinear<F> {
+    type Output = DenseMultilinear<F>;
+
+    fn sub(self, other: &DenseMultilinear<F>) -> DenseMultilinear<F> {
+        self.try_sub(other).expect("The two polynomials must have the same number of variables")
+    }
+}
+
+impl<F: PrimeField> Neg for &DenseMultilinear<F> {
+    type Output = DenseMultilinear<F>;
+
+    fn neg(self) -> DenseMultilinear<F> {
+        self.scalar_mul(-F::one())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fq;
+
+    use super::*;
+    use crate::multilinear::mocks::{multilinear_polya, multilinear_polyb};
+
+    fn dense(evaluations: &[i64]) -> DenseMultilinear<Fq> {
+        DenseMultilinear::new(evaluations.iter().map(|value| Fq::from(*value)).collect()).unwrap()
+    }
+
+    #[test]
+    fn should_convert_from_coefficient_form() {
+        // f(a,b,c) = 2abc + 2ab + 3bc + 4
+        let poly = multilinear_polya::<Fq>();
+        let table = DenseMultilinear::from(&poly);
+        assert_eq!(table, dense(&[4, 4, 4, 7, 4, 4, 6, 11]));
+        assert_eq!(table.sum_over_hypercube(), Fq::from(44));
+
+        assert_eq!(MultiLinearPolynomial::from(&table), poly);
+
+        let poly = multilinear_polyb::<Fq>();
+        assert_eq!(MultiLinearPolynomial::from(&DenseMultilinear::from(&poly)), poly);
+    }
+
+    #[test]
+    fn should_evaluate_at_any_point() {
+        let table = DenseMultilinear::from(&multilinear_polya::<Fq>());
+        // 2(-3) + 2 + 3(-3) + 4
+        assert_eq!(table.evaluate(&[Fq::from(1), Fq::from(1), Fq::from(-3)]), Ok(Fq::from(-9)));
+
+        let point = [Fq::from(5), Fq::from(-2), Fq::from(7)];
+        let poly = multilinear_polya::<Fq>();
+        assert_eq!(table.evaluate(&point).unwrap(), poly.evaluate(&point));
+
+        // On the hypercube the table is read directly
+        assert_eq!(table.evaluate(&[Fq::from(1), Fq::from(1), Fq::from(0)]), Ok(Fq::from(6)));
+    }
+
+    #[test]
+    fn should_fix_variables() {
+        // f(a,b,c) = 2abc + 2ab + 3bc + 4
+        let table = DenseMultilinear::from(&multilinear_polya::<Fq>());
+
+        // f(a,2,c) = 4ac + 4a + 6c + 4
+        let fixed = table.fix_variable(1, Fq::from(2)).unwrap();
+        assert_eq!(fixed.variables(), 2);
+        assert_eq!(fixed, dense(&[4, 10, 8, 18]));
+
+        // f(3,b,c) = 9bc + 6b + 4
+        let fixed = table.fix_variables(&[Fq::from(3)]).unwrap();
+        assert_eq!(fixed, table.fix_variable(0, Fq::from(3)).unwrap());
+        assert_eq!(fixed, dense(&[4, 4, 10, 19]));
+
+        let all = table.fix_variables(&[Fq::from(3), Fq::from(1), Fq::from(1)]).unwrap();
+        assert_eq!(all.variables(), 0);
+        assert_eq!(all.evaluations(), &vec![Fq::from(19)]);
+    }
+
+    #[test]
+    fn should_do_arithmetic() {
+        let table_a = dense(&[1, 2, 3, 4]);
+        let table_b = dense(&[5, -1, 0, 2]);
+
+        assert_eq!(&table_a + &table_b, dense(&[6, 1, 3, 6]));
+        assert_eq!(&table_a - &table_b, dense(&[-4, 3, 3, 2]));
+        assert_eq!(-&table_a, dense(&[-1, -2, -3, -4]));
+        assert_eq!(table_a.scalar_mul(Fq::from(3)), dense(&[3, 6, 9, 12]));
+        assert_eq!((&table_a + &table_b).sum_over_hypercube(), table_a.sum_over_hypercube() + table_b.sum_over_hypercube());
+        assert_eq!(DenseMultilinear::<Fq>::zero(2).sum_over_hypercube(), Fq::from(0));
+    }
+
+    #[test]
+    fn should_reject_invalid_input() {
+        assert_eq!(DenseMultilinear::<Fq>::new(vec![]), Err(PolynomialError::EmptyInput));
+        assert_eq!(
+            DenseMultilinear::new(vec![Fq::from(1); 3]),
+            Err(PolynomialError::LengthMismatch { expected: 4, found: 3 })
+        );
+        assert_eq!(DenseMultilinear::<Fq>::try_zero(64), Err(PolynomialError::DegreeError));
+
+        let table = dense(&[1, 2, 3, 4]);
+        assert_eq!(
+            table.try_add(&dense(&[1, 2])),
+            Err(PolynomialError::VariableCountMismatch { expected: 2, found: 1 })
+        );
+        assert_eq!(
+            table.evaluate(&[Fq::from(1), Fq::from(1), Fq::from(1)]),
+            Err(PolynomialError::VariableCountMismatch { expected: 2, found: 3 })
+        );
+        assert_eq!(
+            table.fix_variable(2, Fq::from(1)),
+            Err(PolynomialError::IndexOutOfRange { index: 2, bound: 2 })
+        );
+    }
+}
@@ -1,6 +1,3 @@
-use ark_ff::PrimeField;
-use num_bigint::BigUint;
-
 pub fn clear_ith_bit(number: u64, i: u64) -> u64 {
     let adjusted_index = 63 - i;
     number & !(1 >> adjusted_index)
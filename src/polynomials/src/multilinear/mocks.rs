@@ -1,7 +1,7 @@
-use ark_bn254::Fq;
-use ark_ff::{PrimeField};
+use ark_ff::PrimeField;
+use rand::Rng;
 
-use super::MultiLinearPolynomial;
+use super::{dense::DenseMultilinear, MultiLinearPolynomial};
 
 pub fn multilinear_polya<F: PrimeField>() -> MultiLinearPolynomial<F> {
     let variables = 3usize;
@@ -29,3 +29,21 @@ pub fn multilinear_polyb<F: PrimeField>() -> MultiLinearPolynomial<F> {
 
     MultiLinearPolynomial::new(variables, coefficients)
 }
+
+// A table of 2^variables random values
+pub fn random_table<F: PrimeField, R: Rng>(variables: usize, rng: &mut R) -> DenseMultilinear<F> {
+    DenseMultilinear::new((0..1 << variables).map(|_| F::from(rng.random::<u64>())).collect()).unwrap()
+}
+
+// eq(r, x) = prod_i (r_i x_i + (1 - r_i)(1 - x_i)) over the hypercube
+pub fn eq_table<F: PrimeField>(r: &[F]) -> DenseMultilinear<F> {
+    let evaluations = (0..1usize << r.len())
+        .map(|point| {
+            r.iter()
+                .enumerate()
+                .map(|(i, r_i)| if point >> (r.len() - 1 - i) & 1 == 1 { *r_i } else { F::one() - r_i })
+                .product()
+        })
+        .collect();
+    DenseMultilinear::new(evaluations).unwrap()
+}
@@ -7,15 +7,21 @@
 // - Coefficients of each combination of the variables
 // The max degree is equal to the number of variables
 
-use ark_bn254::Fq;
 use ark_ff::{BigInteger, PrimeField};
 use num_bigint::BigUint;
 
+pub use crate::error::PolynomialError;
+
 pub mod mocks;
 pub mod helper;
 pub mod arithmetics;
+pub mod dense;
+pub mod virtual_polynomial;
+pub mod transforms;
+
+use transforms::{mobius_transform, zeta_transform};
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct MultiLinearPolynomial<F: PrimeField> {
     // Number of variables is collected to avoid extra computation.
     variables: usize,
@@ -26,30 +32,36 @@ pub struct MultiLinearPolynomial<F: PrimeField> {
 
 impl<F: PrimeField> MultiLinearPolynomial<F> {
     pub fn new(variables: usize, coefficients: Vec<(usize, F)>) -> Self {
-        let exponent = BigUint::from(
-            variables as u64
-        );
-        let combinations = BigUint::from(2u128).modpow(&exponent, &BigUint::from_bytes_le(&F::MODULUS.to_bytes_le()));
-        
-        if F::from(coefficients.len() as u128) > F::from(combinations) {
-            panic!("The coefficients.len() must not be gt the variables");
+        Self::try_new(variables, coefficients).expect("The term indices must be below 2^variables")
+    }
+
+    pub fn try_new(variables: usize, coefficients: Vec<(usize, F)>) -> Result<Self, PolynomialError> {
+        // Every term is a combination of the variables, so its index is below 2^variables
+        if let Some(bound) = 1usize.checked_shl(variables as u32) {
+            if let Some(&(index, _)) = coefficients.iter().find(|(index, _)| *index >= bound) {
+                return Err(PolynomialError::IndexOutOfRange { index, bound });
+            }
         }
 
         let mut mlp = MultiLinearPolynomial { 
             variables,
-            coefficients: coefficients
+            coefficients
         };
         mlp.ensure_sorted();
         mlp.ensure_no_zero_coefficients();
 
         // Assumes the coefficients are sorted 0..variables
-        mlp
+        Ok(mlp)
     }
 
     pub fn coefficients(&self) -> &Vec<(usize, F)> {
         &self.coefficients
     }
 
+    pub fn variables(&self) -> usize {
+        self.variables
+    }
+
     pub fn combinations(&self) -> Vec<u64> {
         let exponent = BigUint::from(self.variables);
         let combinations = BigUint::from(2u128)
@@ -74,7 +86,7 @@ impl<F: PrimeField> MultiLinearPolynomial<F> {
             .collect::<Vec<&bool>>();
 
         // The degree is the length of the true values
-        return F::from(ones_count.len() as u128);
+        F::from(ones_count.len() as u128)
     }
 
     pub fn scalar_mul(&self, scalar: F) -> Self {
@@ -89,7 +101,7 @@ impl<F: PrimeField> MultiLinearPolynomial<F> {
 
     pub fn ensure_sorted(&mut self) -> bool {
         let mut sorted = self.coefficients.clone();
-        sorted.sort_by(|a, b| a.0.cmp(&b.0));
+        sorted.sort_by_key(|a| a.0);
 
         self.coefficients = sorted.to_vec();
 
@@ -113,114 +125,177 @@ impl<F: PrimeField> MultiLinearPolynomial<F> {
 }
 
 impl<F: PrimeField> MultiLinearPolynomial<F> {
-    pub fn evaluate(&mut self, set: Vec<F>) -> F {
-        assert_eq!(
-            F::from(self.variables as u64), 
-            F::from(set.len() as u128), 
-            "Invalid number of variables"
-        );
+    pub fn evaluate(&self, point: &[F]) -> F {
+        self.try_evaluate(point).expect("Invalid number of variables")
+    }
 
-        for (i, value) in set.iter().enumerate() {
-            // Do partial evaluation of the polynomial
-            let _ = self.partial_evaluate(i, *value);
+    // Sum of every coefficient times the values of the variables in its term
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(variables = self.variables, terms = self.coefficients.len())))]
+    pub fn try_evaluate(&self, point: &[F]) -> Result<F, PolynomialError> {
+        if point.len() != self.variables {
+            return Err(PolynomialError::VariableCountMismatch {
+                expected: self.variables,
+                found: point.len(),
+            });
         }
 
-        self.coefficients()[0].1
+        // Every variable is fixed, the terms only carry bits of existing variables
+        Ok(self
+            .coefficients
+            .iter()
+            .map(|(term, coefficient)| *coefficient * self.product_of_fixed(*term, usize::MAX, point))
+            .sum())
     }
 
-    pub fn partial_evaluate(&mut self, index: usize, value: F) -> Result<(), ()> {
-        let mut new_coefficients = self.coefficients.clone();
-        let variable_count = self.variables;
-
-        for (_, (variables, coefficient)) in self.coefficients.iter().enumerate() {
-            // Check index of each variable
-            // If index is 1 multiply coefficient by value and,
-            // Switch the index to 0
-            let variable_bits = format!(
-                "{:0width$b}", 
-                variables, 
-                width = variable_count
-            );
-            let is_1_bit = match variable_bits.chars().nth(index) {
-                Some(bit) => bit == '1',
-                None => false,
-            };
-            
-            if is_1_bit {
-                // clear the i-th bit
-                // let variable = clear_ith_bit(*variables as u64, index as u64) as usize;
-                let variable = variables & !(1 << (variable_count - 1 - index));
-                println!("Variable: {}", variable);
-                // multiply coefficient by value
-                let coefficient = *coefficient * value;
-
-                // Check if the variable is already in the coefficients
-                let has_variable = new_coefficients.iter().any(|(var, _)| *var == variable);
-                if !has_variable {
-                    // Add the variable to the coefficients
-                    new_coefficients.push((variable, coefficient));
-                    new_coefficients = new_coefficients.iter().filter(|(var, _)| var != variables).cloned().collect::<Vec<(usize, F)>>();
-                } else {
-                    // Update the coefficient of the existing variable
-                     new_coefficients = new_coefficients.iter().map(|(var, coeff)| if var == &variable {
-                        return (*var, coefficient + coeff)
-                    } else if var == variables {
-                        return (*var, coefficient * F::ZERO)
-                    } else {
-                        return (*var, *coeff)
-                    }).collect::<Vec<(usize, F)>>();
-                }
+    // Fixes the variable at `index` to `value`.
+    // The number of variables is kept, the fixed one just no longer appears in any term.
+    pub fn partial_evaluate(&self, index: usize, value: F) -> Self {
+        self.try_partial_evaluate(index, value).expect("The variable index must be below the number of variables")
+    }
+
+    pub fn try_partial_evaluate(&self, index: usize, value: F) -> Result<Self, PolynomialError> {
+        self.partial_evaluate_set(&[(index, value)])
+    }
+
+    // Fixes the variables 0..values.len()
+    pub fn partial_evaluate_prefix(&self, values: &[F]) -> Result<Self, PolynomialError> {
+        let assignments: Vec<(usize, F)> = values.iter().copied().enumerate().collect();
+        self.partial_evaluate_set(&assignments)
+    }
+
+    // Fixes the last values.len() variables
+    pub fn partial_evaluate_suffix(&self, values: &[F]) -> Result<Self, PolynomialError> {
+        let start = self.variables.checked_sub(values.len()).ok_or(PolynomialError::VariableCountMismatch {
+            expected: self.variables,
+            found: values.len(),
+        })?;
+        let assignments: Vec<(usize, F)> = values.iter().enumerate().map(|(i, value)| (start + i, *value)).collect();
+        self.partial_evaluate_set(&assignments)
+    }
 
+    // Fixes every (variable, value) pair in one pass over the terms,
+    // a variable given twice keeps its last value
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(fixed = assignments.len(), terms = self.coefficients.len())))]
+    pub fn partial_evaluate_set(&self, assignments: &[(usize, F)]) -> Result<Self, PolynomialError> {
+        // The value of every variable by index and the bits of the fixed ones
+        let mut values = vec![F::zero(); self.variables];
+        let mut fixed = 0usize;
+        for (index, value) in assignments {
+            if *index >= self.variables {
+                return Err(PolynomialError::IndexOutOfRange {
+                    index: *index,
+                    bound: self.variables,
+                });
+            }
+            values[*index] = *value;
+            fixed |= self.bit(*index);
+        }
+
+        // Terms that only differ in fixed variables fall on the same index
+        let mut terms: Vec<(usize, F)> = self
+            .coefficients
+            .iter()
+            .map(|(term, coefficient)| (term & !fixed, *coefficient * self.product_of_fixed(*term, fixed, &values)))
+            .collect();
+        terms.sort_by_key(|(term, _)| *term);
+        let mut merged: Vec<(usize, F)> = Vec::with_capacity(terms.len());
+        for (term, coefficient) in terms {
+            match merged.last_mut() {
+                Some((last, sum)) if *last == term => *sum += coefficient,
+                _ => merged.push((term, coefficient)),
             }
         }
 
-        *self = MultiLinearPolynomial::new(self.variables, new_coefficients);
-        Ok(())
+        MultiLinearPolynomial::try_new(self.variables, merged)
+    }
+
+    // The mask of variable `index`, variable 0 is the most significant bit.
+    // Variables beyond the bits of a usize cannot appear in any term, their mask is 0.
+    fn bit(&self, index: usize) -> usize {
+        1usize.checked_shl((self.variables - 1 - index) as u32).unwrap_or(0)
+    }
+
+    // Product of values[i] over the variables i of `term` that are in `fixed`
+    fn product_of_fixed(&self, term: usize, fixed: usize, values: &[F]) -> F {
+        let mut product = F::one();
+        let mut bits = term & fixed;
+        while bits != 0 {
+            let position = bits.trailing_zeros() as usize;
+            product *= values[self.variables - 1 - position];
+            bits &= bits - 1;
+        }
+        product
     }
 
     /**
-     * Takes a Vec of points (x, y) where x is the variable combination
+     * Takes the evaluation of every variable combination
      * Should return a multilinear polynomial
-     * We need the evaluation of each combination of the polynomial
-     * @params points: Vec<(usize, F)>, F here is the evaluation of the points
+     * points[i] is the evaluation at the combination given by the bits of i, variable 0 first
+     * @params points: Vec<usize>, the 2^variables evaluations
      * @params variables: usize, the total number of variables
      * @return: MultiLinearPolynomial<F>
-     * @example: points = [(0, 1), (1, 2), (2, 3)]
+     * @example: points = [6, 9, 8, 6] for f(a,b) = 2a + 3b - 5ab + 6
      */
     pub fn interpolate(points: Vec<usize>, variables: usize) -> MultiLinearPolynomial<F> {
-        assert_eq!((points.len() as f64).log2(), variables as f64);
-        let mut coefficients = MultiLinearPolynomial::new(variables, vec![]);
-        
-        // // Iterate through the points and create a new polynomial
-        for (index, coefficient) in points.iter().enumerate() {
-            // y . if variable[i] == (1 - a) (check_1) else (a)
-            // Get the variable combination
-            let variable_bits = format!("{:0width$b}", index, width = variables);
-            let mut variable_product = MultiLinearPolynomial::new(variables, vec![(0, F::ONE)]);
-            for (bit, data) in variable_bits.chars().enumerate() {
-                if data == '1' {
-                    // check_1
-                    variable_product = &variable_product * &MultiLinearPolynomial::new(variables, [(0, F::ZERO), (2usize.pow(bit as u32), F::from(1))].to_vec());
-                } else {
-                    // check_0
-                    variable_product = &variable_product * &MultiLinearPolynomial::new(variables, [(0, F::ONE), (2usize.pow(bit as u32), F::from(-1))].to_vec());
-                }
-            }
+        Self::try_interpolate(points, variables).expect("There must be 2^variables points")
+    }
 
-            coefficients = &coefficients + &variable_product.scalar_mul(F::from(*coefficient as u64));
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(variables = variables)))]
+    pub fn try_interpolate(points: Vec<usize>, variables: usize) -> Result<MultiLinearPolynomial<F>, PolynomialError> {
+        if 1usize.checked_shl(variables as u32) != Some(points.len()) {
+            return Err(PolynomialError::LengthMismatch {
+                expected: 1usize.checked_shl(variables as u32).unwrap_or(usize::MAX),
+                found: points.len(),
+            });
         }
-        
-        coefficients
+
+        Self::from_evaluations(points.iter().map(|point| F::from(*point as u64)).collect())
+    }
+
+    // The unique polynomial taking evaluations[i] at the point given by the bits of i,
+    // through the Möbius transform in O(n 2^n)
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(points = evaluations.len())))]
+    pub fn from_evaluations(mut evaluations: Vec<F>) -> Result<MultiLinearPolynomial<F>, PolynomialError> {
+        if !evaluations.len().is_power_of_two() {
+            return Err(PolynomialError::LengthMismatch {
+                expected: evaluations.len().next_power_of_two(),
+                found: evaluations.len(),
+            });
+        }
+        let variables = evaluations.len().trailing_zeros() as usize;
+
+        mobius_transform(&mut evaluations);
+        MultiLinearPolynomial::try_new(variables, evaluations.into_iter().enumerate().collect())
+    }
+
+    // The 2^n evaluations over the hypercube through the zeta transform, undoes `from_evaluations`
+    pub fn to_evaluations(&self) -> Vec<F> {
+        self.try_to_evaluations().expect("The table of 2^variables entries must fit in memory")
+    }
+
+    // Fails when 2^n does not fit in a usize
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(variables = self.variables, terms = self.coefficients.len())))]
+    pub fn try_to_evaluations(&self) -> Result<Vec<F>, PolynomialError> {
+        let size = u32::try_from(self.variables)
+            .ok()
+            .and_then(|variables| 1usize.checked_shl(variables))
+            .ok_or(PolynomialError::DegreeError)?;
+        let mut values = vec![F::zero(); size];
+        for (index, coefficient) in self.coefficients.iter() {
+            values[*index] = *coefficient;
+        }
+
+        zeta_transform(&mut values);
+        Ok(values)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{mocks::{multilinear_polya, multilinear_polyb}, MultiLinearPolynomial};
+    use super::{mocks::{multilinear_polya, multilinear_polyb}, MultiLinearPolynomial, PolynomialError};
     // use super::*;
 
     use ark_bn254::Fq;
-    use ark_ff::{Field, PrimeField};
 
     #[test]
     fn should_initialize_multilinear_polynomial() {
@@ -259,8 +334,9 @@ mod tests {
         (56, Fq::from(4u128)),
         (57, Fq::from(2u128)),
     ];
-    let mut poly = MultiLinearPolynomial::new(6, coefficients);
-    let _ = poly.partial_evaluate(2, Fq::from(2));
+    let original = MultiLinearPolynomial::new(6, coefficients);
+    let poly = original.partial_evaluate(2, Fq::from(2));
+    assert_eq!(original.coefficients()[1], (28, Fq::from(3)));
 
     assert_eq!(poly.coefficients()[4].1, Fq::from(4));
     assert_eq!(poly.coefficients()[2].0, 21);
@@ -274,14 +350,116 @@ mod tests {
             2
         );
         assert_eq!(polynomial.coefficients().len(), 4);
+        assert_eq!(polynomial.coefficients()[1], (1, Fq::from(3)));
+        assert_eq!(polynomial.coefficients()[2], (2, Fq::from(2)));
         assert_eq!(polynomial.coefficients()[3].1, Fq::from(-5));
         
         // let poly = f(a,b,c) = 3ab + 12abc - 4bc - c + 15
         let polynomial: MultiLinearPolynomial<Fq> = MultiLinearPolynomial::interpolate(
             vec![15, 14, 15, 10, 15, 14, 18, 25], 3
         );
-        assert_eq!(polynomial.coefficients().len(), 5);
-        assert_eq!(polynomial.coefficients()[4].1, Fq::from(12));
-        assert_eq!(polynomial.coefficients()[3].1, Fq::from(-4));
+        assert_eq!(
+            polynomial.coefficients(),
+            &vec![(0, Fq::from(15)), (1, Fq::from(-1)), (3, Fq::from(-4)), (6, Fq::from(3)), (7, Fq::from(12))]
+        );
+        assert_eq!(polynomial.to_evaluations(), [15, 14, 15, 10, 15, 14, 18, 25].map(Fq::from).to_vec());
+    }
+
+    #[test]
+    fn should_convert_to_evaluations() {
+        // f(a,b,c) = 2abc + 2ab + 3bc + 4
+        let poly = multilinear_polya::<Fq>();
+        let evaluations = poly.to_evaluations();
+        assert_eq!(evaluations, [4, 4, 4, 7, 4, 4, 6, 11].map(Fq::from).to_vec());
+        assert_eq!(MultiLinearPolynomial::from_evaluations(evaluations), Ok(poly));
+
+        let poly = multilinear_polyb::<Fq>();
+        assert_eq!(MultiLinearPolynomial::from_evaluations(poly.to_evaluations()), Ok(poly));
+        assert_eq!(
+            MultiLinearPolynomial::<Fq>::new(64, vec![(0, Fq::from(3))]).try_to_evaluations(),
+            Err(PolynomialError::DegreeError)
+        );
+
+        assert_eq!(
+            MultiLinearPolynomial::<Fq>::from_evaluations(vec![Fq::from(1); 6]),
+            Err(PolynomialError::LengthMismatch { expected: 8, found: 6 })
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_input() {
+        // 2 variables only have the terms 0..4
+        assert_eq!(
+            MultiLinearPolynomial::try_new(2, vec![(4, Fq::from(1))]).unwrap_err(),
+            PolynomialError::IndexOutOfRange { index: 4, bound: 4 }
+        );
+
+        let poly = multilinear_polya::<Fq>();
+        assert_eq!(
+            poly.try_evaluate(&[Fq::from(1)]),
+            Err(PolynomialError::VariableCountMismatch { expected: 3, found: 1 })
+        );
+        assert_eq!(
+            poly.try_partial_evaluate(3, Fq::from(1)),
+            Err(PolynomialError::IndexOutOfRange { index: 3, bound: 3 })
+        );
+        assert_eq!(
+            poly.partial_evaluate_suffix(&[Fq::from(1); 4]),
+            Err(PolynomialError::VariableCountMismatch { expected: 3, found: 4 })
+        );
+        assert_eq!(
+            MultiLinearPolynomial::<Fq>::try_interpolate(vec![1, 2, 3], 2).unwrap_err(),
+            PolynomialError::LengthMismatch { expected: 4, found: 3 }
+        );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn should_evaluate_to_zero() {
+        // f(a,b,c) = 2abc + 2ab + 3bc + 4 at (1, 1, -3): 2(-3) + 2 + 3(-3) + 4 = -9
+        let poly = multilinear_polya::<Fq>();
+        assert_eq!(poly.try_evaluate(&[Fq::from(1), Fq::from(1), Fq::from(-3)]), Ok(Fq::from(-9)));
+
+        // at (0, 0, 0) only the constant 4 is left, 4 - 4 vanishes
+        let poly = &multilinear_polya::<Fq>() + &MultiLinearPolynomial::new(3, vec![(0, Fq::from(-4))]);
+        assert_eq!(poly.try_evaluate(&[Fq::from(0), Fq::from(0), Fq::from(0)]), Ok(Fq::from(0)));
+        assert_eq!(MultiLinearPolynomial::<Fq>::new(2, vec![]).evaluate(&[Fq::from(3), Fq::from(5)]), Fq::from(0));
+    }
+
+    #[test]
+    fn should_fix_a_batch_of_variables() {
+        // f(a,b,c) = 2abc + 2ab + 3bc + 4
+        let poly = multilinear_polya::<Fq>();
+        let (a, b, c) = (Fq::from(5), Fq::from(-2), Fq::from(7));
+
+        // f(5,-2,c) = -20c - 20 - 6c + 4 = -26c - 16
+        let prefix = poly.partial_evaluate_prefix(&[a, b]).unwrap();
+        assert_eq!(prefix, MultiLinearPolynomial::new(3, vec![(0, Fq::from(-16)), (1, Fq::from(-26))]));
+        assert_eq!(prefix, poly.partial_evaluate(0, a).partial_evaluate(1, b));
+
+        // f(a,-2,7) = -28a - 4a - 42 + 4 = -32a - 38
+        let suffix = poly.partial_evaluate_suffix(&[b, c]).unwrap();
+        assert_eq!(suffix, MultiLinearPolynomial::new(3, vec![(0, Fq::from(-38)), (4, Fq::from(-32))]));
+
+        let set = poly.partial_evaluate_set(&[(2, c), (0, a)]).unwrap();
+        assert_eq!(set, poly.partial_evaluate(0, a).partial_evaluate(2, c));
+        assert_eq!(set.evaluate(&[Fq::from(0), b, Fq::from(0)]), poly.evaluate(&[a, b, c]));
+
+        // Fixing everything leaves the constant f(a, b, c)
+        let all = poly.partial_evaluate_prefix(&[a, b, c]).unwrap();
+        assert_eq!(all, MultiLinearPolynomial::new(3, vec![(0, poly.evaluate(&[a, b, c]))]));
+    }
+
+    #[test]
+    fn should_evaluate_with_many_variables() {
+        // 3 + 2x_63 in 64 variables
+        let poly = MultiLinearPolynomial::new(64, vec![(0, Fq::from(3)), (1, Fq::from(2))]);
+        assert_eq!(poly.evaluate(&[Fq::from(1); 64]), Fq::from(5));
+
+        // 3 + 2x_69 in 70 variables, x_0..x_5 never appear in a term
+        let poly = MultiLinearPolynomial::new(70, vec![(0, Fq::from(3)), (1, Fq::from(2))]);
+        assert_eq!(poly.partial_evaluate(0, Fq::from(9)), poly);
+        let fixed = poly.partial_evaluate(69, Fq::from(5));
+        assert_eq!(fixed, MultiLinearPolynomial::new(70, vec![(0, Fq::from(13))]));
+        assert_eq!(poly.evaluate(&[Fq::from(5); 70]), Fq::from(13));
+    }
+}
@@ -0,0 +1,74 @@
+// Subset-sum transforms between the two forms of a multilinear polynomial in n variables.
+// Both the term indices and the hypercube points are n-bit masks, variable 0 being the most significant bit.
+//
+// - Zeta: coefficients to evaluations, f(x) = sum of c_S over S ⊆ x
+// - Möbius: evaluations to coefficients, c_S = sum over T ⊆ S of (-1)^|S \ T| f(T)
+//
+// Each handles one variable at a time, adding (or subtracting) the half of the table
+// without the variable into the half with it, so both are O(n 2^n) and in place.
+
+use ark_ff::PrimeField;
+
+// Coefficients, indexed by term, become the evaluations over the hypercube
+pub fn zeta_transform<F: PrimeField>(values: &mut [F]) {
+    assert!(values.len().is_power_of_two(), "The table size must be a power of two");
+
+    for_each_pair(values, |without, with| *with += without);
+}
+
+// Evaluations over the hypercube become the coefficients, undoes `zeta_transform`
+pub fn mobius_transform<F: PrimeField>(values: &mut [F]) {
+    assert!(values.len().is_power_of_two(), "The table size must be a power of two");
+
+    for_each_pair(values, |without, with| *with -= without);
+}
+
+// Calls `op` on (values[i], values[i | bit]) for every variable bit and every i without it
+fn for_each_pair<F: PrimeField>(values: &mut [F], op: impl Fn(F, &mut F)) {
+    let mut bit = 1;
+    while bit < values.len() {
+        for chunk in values.chunks_mut(2 * bit) {
+            let (without, with) = chunk.split_at_mut(bit);
+            for (a, b) in without.iter().zip(with.iter_mut()) {
+                op(*a, b);
+            }
+        }
+        bit *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fq;
+
+    use super::*;
+
+    fn table(values: &[i64]) -> Vec<Fq> {
+        values.iter().map(|value| Fq::from(*value)).collect()
+    }
+
+    #[test]
+    fn should_transform_between_forms() {
+        // f(a,b,c) = 2abc + 2ab + 3bc + 4
+        let coefficients = table(&[4, 0, 0, 3, 0, 0, 2, 2]);
+        let evaluations = table(&[4, 4, 4, 7, 4, 4, 6, 11]);
+
+        let mut values = coefficients.clone();
+        zeta_transform(&mut values);
+        assert_eq!(values, evaluations);
+
+        mobius_transform(&mut values);
+        assert_eq!(values, coefficients);
+
+        // A single value is its own transform
+        let mut constant = table(&[5]);
+        zeta_transform(&mut constant);
+        assert_eq!(constant, table(&[5]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_transform_partial_tables() {
+        mobius_transform(&mut table(&[1, 2, 3]));
+    }
+}
@@ -0,0 +1,226 @@
+// A sum of products of multilinear polynomials in the same n variables,
+//     f(x) = sum_t c_t * prod_j m_(t,j)(x)
+// e.g. eq(r,x) * A(x) * B(x). f is not multilinear, its degree in each variable
+// is the largest number of factors of a term, so it is kept as the factor tables.
+//
+// The round polynomial of sum-check, g(X) = sum of f(X, b) over b in {0,1}^(n-1),
+// has that degree d. Every factor is linear in X, m(X, b) = m(0, b) + X (m(1, b) - m(0, b)),
+// so g is evaluated at X = 0, ..., d in one pass over the tables and interpolated.
+
+use ark_ff::PrimeField;
+
+use super::{dense::DenseMultilinear, PolynomialError};
+use crate::univariate::{types::PolynomialTrait, Polynomials};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VirtualPolynomial<F: PrimeField> {
+    variables: usize,
+    // (coefficient, factors) for every term
+    terms: Vec<(F, Vec<DenseMultilinear<F>>)>,
+}
+
+impl<F: PrimeField> VirtualPolynomial<F> {
+    // The zero polynomial in `variables` variables, terms are added with `add_term`
+    pub fn new(variables: usize) -> Self {
+        VirtualPolynomial {
+            variables,
+            terms: vec![],
+        }
+    }
+
+    // Every factor must have the same number of variables
+    pub fn from_terms(variables: usize, terms: Vec<(F, Vec<DenseMultilinear<F>>)>) -> Result<Self, PolynomialError> {
+        let mut poly = Self::new(variables);
+        for (coefficient, factors) in terms {
+            poly.add_term(coefficient, factors)?;
+        }
+        Ok(poly)
+    }
+
+    // Adds coefficient * prod factors
+    pub fn add_term(&mut self, coefficient: F, factors: Vec<DenseMultilinear<F>>) -> Result<(), PolynomialError> {
+        if let Some(factor) = factors.iter().find(|factor| factor.variables() != self.variables) {
+            return Err(PolynomialError::VariableCountMismatch {
+                expected: self.variables,
+                found: factor.variables(),
+            });
+        }
+
+        self.terms.push((coefficient, factors));
+        Ok(())
+    }
+
+    pub fn variables(&self) -> usize {
+        self.variables
+    }
+
+    pub fn terms(&self) -> &Vec<(F, Vec<DenseMultilinear<F>>)> {
+        &self.terms
+    }
+
+    // Degree in each variable, the largest number of factors of a term
+    pub fn degree(&self) -> usize {
+        self.terms.iter().map(|(_, factors)| factors.len()).max().unwrap_or(0)
+    }
+
+    pub fn evaluate(&self, point: &[F]) -> Result<F, PolynomialError> {
+        if point.len() != self.variables {
+            return Err(PolynomialError::VariableCountMismatch {
+                expected: self.variables,
+                found: point.len(),
+            });
+        }
+
+        let mut sum = F::zero();
+        for (coefficient, factors) in self.terms.iter() {
+            let mut product = *coefficient;
+            for factor in factors {
+                product *= factor.evaluate(point)?;
+            }
+            sum += product;
+        }
+        Ok(sum)
+    }
+
+    // Sum of f over {0,1}^n, a product of table entries per point and term
+    pub fn sum_over_hypercube(&self) -> F {
+        self.terms
+            .iter()
+            .map(|(coefficient, factors)| {
+                let sum: F = (0..1usize << self.variables)
+                    .map(|point| factors.iter().map(|factor| factor.evaluations()[point]).product::<F>())
+                    .sum();
+                *coefficient * sum
+            })
+            .sum()
+    }
+
+    // Fixes the first values.len() variables in every factor
+    pub fn fix_variables(&self, values: &[F]) -> Result<Self, PolynomialError> {
+        if values.len() > self.variables {
+            return Err(PolynomialError::VariableCountMismatch {
+                expected: self.variables,
+                found: values.len(),
+            });
+        }
+
+        let terms = self
+            .terms
+            .iter()
+            .map(|(coefficient, factors)| {
+                let fixed = factors.iter().map(|factor| factor.fix_variables(values)).collect::<Result<Vec<_>, _>>()?;
+                Ok((*coefficient, fixed))
+            })
+            .collect::<Result<Vec<_>, PolynomialError>>()?;
+
+        Ok(VirtualPolynomial {
+            variables: self.variables - values.len(),
+            terms,
+        })
+    }
+
+    // g(X) = sum of f(X, b) over b in {0,1}^(n-1), of degree at most `degree()`.
+    // O(d * |factors| * 2^n) for the evaluations at X = 0, ..., d and O(d^2) to interpolate them.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(variables = self.variables, terms = self.terms.len())))]
+    pub fn round_polynomial(&self) -> Polynomials<F> {
+        assert!(self.variables > 0, "The round polynomial needs a free variable");
+
+        let degree = self.degree();
+        let half = 1usize << (self.variables - 1);
+        // sums[k] = g(k)
+        let mut sums = vec![F::zero(); degree + 1];
+        let mut products = vec![F::zero(); degree + 1];
+
+        for (coefficient, factors) in self.terms.iter() {
+            for b in 0..half {
+                products.iter_mut().for_each(|product| *product = *coefficient);
+                for factor in factors {
+                    // m(k, b) for k = 0, 1, 2, ... steps by m(1, b) - m(0, b)
+                    let mut value = factor.evaluations()[b];
+                    let step = factor.evaluations()[half + b] - value;
+                    for product in products.iter_mut() {
+                        *product *= value;
+                        value += step;
+                    }
+                }
+                for (sum, product) in sums.iter_mut().zip(products.iter()) {
+                    *sum += product;
+                }
+            }
+        }
+
+        let points = sums.into_iter().enumerate().map(|(k, sum)| (F::from(k as u64), sum)).collect();
+        Polynomials::try_interpolate(points).expect("The points 0, ..., d are distinct")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fr;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+    use crate::multilinear::mocks::{eq_table, random_table};
+
+    #[test]
+    fn should_evaluate_sums_of_products() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let (a, b, c) = (random_table::<Fr, _>(3, &mut rng), random_table(3, &mut rng), random_table(3, &mut rng));
+        // 3ab - 2c + 5
+        let poly = VirtualPolynomial::from_terms(
+            3,
+            vec![(Fr::from(3), vec![a.clone(), b.clone()]), (Fr::from(-2), vec![c.clone()]), (Fr::from(5), vec![])],
+        )
+        .unwrap();
+        assert_eq!(poly.degree(), 2);
+
+        let point = [Fr::from(4), Fr::from(-1), Fr::from(9)];
+        let expected = Fr::from(3) * a.evaluate(&point).unwrap() * b.evaluate(&point).unwrap()
+            - Fr::from(2) * c.evaluate(&point).unwrap()
+            + Fr::from(5);
+        assert_eq!(poly.evaluate(&point), Ok(expected));
+
+        let expected_sum: Fr = (0..8)
+            .map(|i| Fr::from(3) * a.evaluations()[i] * b.evaluations()[i] - Fr::from(2) * c.evaluations()[i] + Fr::from(5))
+            .sum();
+        assert_eq!(poly.sum_over_hypercube(), expected_sum);
+
+        // Fixing variables commutes with evaluation
+        let fixed = poly.fix_variables(&point[..2]).unwrap();
+        assert_eq!(fixed.variables(), 1);
+        assert_eq!(fixed.evaluate(&point[2..]), Ok(expected));
+    }
+
+    #[test]
+    fn should_compute_round_polynomials() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let r: Vec<Fr> = (0..4).map(|_| Fr::from(rng.random::<u64>())).collect();
+        let (a, b) = (random_table(4, &mut rng), random_table(4, &mut rng));
+        // eq(r, x) * A(x) * B(x) has degree 3 in each variable
+        let poly = VirtualPolynomial::from_terms(4, vec![(Fr::from(1), vec![eq_table(&r), a, b])]).unwrap();
+
+        let round = poly.round_polynomial();
+        assert!(round.degree().is_some_and(|degree| degree <= 3));
+        assert_eq!(round.evaluate(Fr::from(0)) + round.evaluate(Fr::from(1)), poly.sum_over_hypercube());
+
+        // g(X) is the sum of the polynomial with the first variable fixed to X
+        let x = Fr::from(rng.random::<u64>());
+        assert_eq!(round.evaluate(x), poly.fix_variables(&[x]).unwrap().sum_over_hypercube());
+    }
+
+    #[test]
+    fn should_reject_mismatched_factors() {
+        let mut rng = StdRng::seed_from_u64(17);
+        let mut poly = VirtualPolynomial::new(3);
+        assert_eq!(
+            poly.add_term(Fr::from(1), vec![random_table(3, &mut rng), random_table(2, &mut rng)]),
+            Err(PolynomialError::VariableCountMismatch { expected: 3, found: 2 })
+        );
+        assert!(poly.terms().is_empty());
+        assert_eq!(
+            poly.evaluate(&[Fr::from(1)]),
+            Err(PolynomialError::VariableCountMismatch { expected: 3, found: 1 })
+        );
+        assert_eq!(poly.sum_over_hypercube(), Fr::from(0));
+    }
+}
@@ -0,0 +1,220 @@
+// Binary encodings of the polynomial types with arkworks' CanonicalSerialize,
+// and with the `serde` feature a human-readable form where every field element
+// is the hex string of its compressed canonical bytes:
+//     Polynomials             {"coefficients": ["05000000...", ...]}
+//     MultiLinearPolynomial   {"variables": 3, "terms": [[0, "04000000..."], ...]}
+// Deserialization rejects empty coefficient vectors and term indices of 2^variables or more.
+
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate, Write};
+
+use crate::{multilinear::MultiLinearPolynomial, univariate::Polynomials};
+
+// The coefficient vector, lowest power first
+impl<F: PrimeField> CanonicalSerialize for Polynomials<F> {
+    fn serialize_with_mode<W: Write>(&self, writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.coefficients().serialize_with_mode(writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.coefficients().serialized_size(compress)
+    }
+}
+
+impl<F: PrimeField> Valid for Polynomials<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        F::batch_check(self.coefficients().iter())
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for Polynomials<F> {
+    fn deserialize_with_mode<R: Read>(reader: R, compress: Compress, validate: Validate) -> Result<Self, SerializationError> {
+        let coefficients = Vec::<F>::deserialize_with_mode(reader, compress, validate)?;
+
+        Polynomials::new(coefficients).map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+// The number of variables followed by the (index, coefficient) terms
+impl<F: PrimeField> CanonicalSerialize for MultiLinearPolynomial<F> {
+    fn serialize_with_mode<W: Write>(&self, mut writer: W, compress: Compress) -> Result<(), SerializationError> {
+        self.variables().serialize_with_mode(&mut writer, compress)?;
+        self.coefficients().serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        self.variables().serialized_size(compress) + self.coefficients().serialized_size(compress)
+    }
+}
+
+impl<F: PrimeField> Valid for MultiLinearPolynomial<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        F::batch_check(self.coefficients().iter().map(|(_, coefficient)| coefficient))
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for MultiLinearPolynomial<F> {
+    fn deserialize_with_mode<R: Read>(mut reader: R, compress: Compress, validate: Validate) -> Result<Self, SerializationError> {
+        let variables = usize::deserialize_with_mode(&mut reader, compress, validate)?;
+        let terms = Vec::<(usize, F)>::deserialize_with_mode(&mut reader, compress, validate)?;
+
+        MultiLinearPolynomial::try_new(variables, terms).map_err(|_| SerializationError::InvalidData)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use ark_ff::PrimeField;
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{multilinear::MultiLinearPolynomial, univariate::Polynomials};
+
+    #[derive(Serialize, Deserialize)]
+    struct PolynomialsRepr {
+        coefficients: Vec<String>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct MultiLinearRepr {
+        variables: usize,
+        terms: Vec<(usize, String)>,
+    }
+
+    fn to_hex<F: PrimeField>(value: &F) -> String {
+        let mut bytes = vec![];
+        value.serialize_compressed(&mut bytes).expect("Writing to a vector never fails");
+        hex::encode(bytes)
+    }
+
+    fn from_hex<F: PrimeField, E: Error>(value: &str) -> Result<F, E> {
+        let bytes = hex::decode(value).map_err(E::custom)?;
+        F::deserialize_compressed(bytes.as_slice()).map_err(E::custom)
+    }
+
+    impl<F: PrimeField> Serialize for Polynomials<F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            PolynomialsRepr {
+                coefficients: self.coefficients().iter().map(to_hex).collect(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, F: PrimeField> Deserialize<'de> for Polynomials<F> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let repr = PolynomialsRepr::deserialize(deserializer)?;
+            let coefficients = repr
+                .coefficients
+                .iter()
+                .map(|coefficient| from_hex(coefficient))
+                .collect::<Result<Vec<F>, D::Error>>()?;
+
+            Polynomials::new(coefficients).map_err(D::Error::custom)
+        }
+    }
+
+    impl<F: PrimeField> Serialize for MultiLinearPolynomial<F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            MultiLinearRepr {
+                variables: self.variables(),
+                terms: self
+                    .coefficients()
+                    .iter()
+                    .map(|(index, coefficient)| (*index, to_hex(coefficient)))
+                    .collect(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, F: PrimeField> Deserialize<'de> for MultiLinearPolynomial<F> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let repr = MultiLinearRepr::deserialize(deserializer)?;
+            let terms = repr
+                .terms
+                .iter()
+                .map(|(index, coefficient)| Ok((*index, from_hex(coefficient)?)))
+                .collect::<Result<Vec<(usize, F)>, D::Error>>()?;
+
+            MultiLinearPolynomial::try_new(repr.variables, terms).map_err(D::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fq;
+
+    use super::*;
+    use crate::{multilinear::mocks::multilinear_polya, univariate::mocks::poly};
+
+    #[test]
+    fn should_round_trip_univariate_polynomials() {
+        // 3x^5 + 5x^3 - 7x - 5
+        let original = poly(&[-5, -7, 0, 5, 0, 3]);
+
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = vec![];
+            original.serialize_with_mode(&mut bytes, compress).unwrap();
+            assert_eq!(bytes.len(), original.serialized_size(compress));
+
+            let decoded = Polynomials::<Fq>::deserialize_with_mode(bytes.as_slice(), compress, Validate::Yes).unwrap();
+            assert_eq!(decoded, original);
+        }
+    }
+
+    #[test]
+    fn should_round_trip_multilinear_polynomials() {
+        let original = multilinear_polya::<Fq>();
+
+        for compress in [Compress::Yes, Compress::No] {
+            let mut bytes = vec![];
+            original.serialize_with_mode(&mut bytes, compress).unwrap();
+            assert_eq!(bytes.len(), original.serialized_size(compress));
+
+            let decoded = MultiLinearPolynomial::<Fq>::deserialize_with_mode(bytes.as_slice(), compress, Validate::Yes).unwrap();
+            assert_eq!(decoded, original);
+        }
+    }
+
+    #[test]
+    fn should_reject_invalid_encodings() {
+        // An empty coefficient vector
+        let mut bytes = vec![];
+        Vec::<Fq>::new().serialize_compressed(&mut bytes).unwrap();
+        assert!(matches!(Polynomials::<Fq>::deserialize_compressed(bytes.as_slice()), Err(SerializationError::InvalidData)));
+
+        // The index 4 is out of range for 2 variables
+        let mut bytes = vec![];
+        2usize.serialize_compressed(&mut bytes).unwrap();
+        vec![(1usize, Fq::from(3)), (4usize, Fq::from(5))].serialize_compressed(&mut bytes).unwrap();
+        assert!(matches!(
+            MultiLinearPolynomial::<Fq>::deserialize_compressed(bytes.as_slice()),
+            Err(SerializationError::InvalidData)
+        ));
+
+        // Truncated input
+        let mut bytes = vec![];
+        poly::<Fq>(&[1, 2, 3]).serialize_compressed(&mut bytes).unwrap();
+        bytes.pop();
+        assert!(Polynomials::<Fq>::deserialize_compressed(bytes.as_slice()).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_round_trip_through_json() {
+        let original = poly(&[-5, -7, 0, 5, 0, 3]);
+        let json = serde_json::to_string(&original).unwrap();
+        assert!(json.starts_with("{\"coefficients\":[\""));
+        assert_eq!(serde_json::from_str::<Polynomials<Fq>>(&json).unwrap(), original);
+
+        let original = multilinear_polya::<Fq>();
+        let json = serde_json::to_string(&original).unwrap();
+        assert!(json.starts_with("{\"variables\":3,\"terms\":[[0,\""));
+        assert_eq!(serde_json::from_str::<MultiLinearPolynomial<Fq>>(&json).unwrap(), original);
+
+        assert!(serde_json::from_str::<Polynomials<Fq>>("{\"coefficients\":[]}").is_err());
+        assert!(serde_json::from_str::<Polynomials<Fq>>("{\"coefficients\":[\"zz\"]}").is_err());
+        assert!(serde_json::from_str::<MultiLinearPolynomial<Fq>>("{\"variables\":1,\"terms\":[[2,\"00\"]]}").is_err());
+    }
+}
@@ -0,0 +1,319 @@
+// Sum-check protocol: the prover convinces the verifier that
+//     H = sum of f(b) over b in {0,1}^n
+// for a polynomial f in n variables, with n rounds instead of 2^n evaluations.
+//
+// In round i the prover sends g_i(X) = sum of f(r_1, ..., r_(i-1), X, b) over b in {0,1}^(n-i).
+// The verifier checks g_i(0) + g_i(1) against the previous claim (H for the first round),
+// answers with a random r_i and takes g_i(r_i) as the next claim.
+// After n rounds it is left with the single claim f(r_1, ..., r_n) = g_n(r_n),
+// checked with one evaluation of f (or handed on to a polynomial commitment).
+//
+// f is anything implementing `SumcheckPolynomial`: a multilinear polynomial given as a table
+// or by its terms (linear rounds), or a `VirtualPolynomial`, a sum of products of multilinears
+// (rounds of degree d).
+//
+// Interactive use drives `Prover` and `Verifier` round by round,
+// `prove` and `verify` draw the challenges from a Fiat-Shamir `Transcript` instead.
+
+use std::{error::Error, fmt};
+
+use ark_ff::PrimeField;
+
+use crate::{error::PolynomialError, univariate::Polynomials};
+
+pub mod polynomial;
+pub mod prover;
+pub mod transcript;
+pub mod verifier;
+
+use polynomial::SumcheckPolynomial;
+use prover::Prover;
+use transcript::Transcript;
+use verifier::Verifier;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SumcheckError {
+    // The claimed sum is not the sum of the polynomial over the hypercube
+    WrongClaim,
+    // g_i(0) + g_i(1) differs from the claim of the previous round
+    RoundSumMismatch { round: usize },
+    // A round polynomial is above the degree bound of the protocol
+    DegreeTooHigh { round: usize, degree: usize, bound: usize },
+    // There must be exactly one round per variable
+    RoundCountMismatch { expected: usize, found: usize },
+    // The prover was asked for a round after fixing every variable
+    NoRoundsLeft,
+    // f(r_1, ..., r_n) differs from the claim of the last round
+    FinalEvaluationMismatch,
+    // A polynomial operation failed, e.g. on a point of the wrong length
+    Polynomial(PolynomialError),
+}
+
+impl fmt::Display for SumcheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SumcheckError::WrongClaim => write!(f, "the claimed sum is not the sum over the hypercube"),
+            SumcheckError::RoundSumMismatch { round } => {
+                write!(f, "the polynomial of round {} does not sum to the previous claim", round)
+            }
+            SumcheckError::DegreeTooHigh { round, degree, bound } => {
+                write!(f, "the polynomial of round {} has degree {}, above the bound {}", round, degree, bound)
+            }
+            SumcheckError::RoundCountMismatch { expected, found } => {
+                write!(f, "expected {} rounds, found {}", expected, found)
+            }
+            SumcheckError::NoRoundsLeft => write!(f, "every variable is already fixed, there is no round left"),
+            SumcheckError::FinalEvaluationMismatch => write!(f, "the final evaluation does not match the last claim"),
+            SumcheckError::Polynomial(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for SumcheckError {}
+
+impl From<PolynomialError> for SumcheckError {
+    fn from(error: PolynomialError) -> Self {
+        SumcheckError::Polynomial(error)
+    }
+}
+
+// What is left to check once every round passed: f(point) = expected_evaluation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Subclaim<F: PrimeField> {
+    point: Vec<F>,
+    expected_evaluation: F,
+}
+
+impl<F: PrimeField> Subclaim<F> {
+    pub fn point(&self) -> &Vec<F> {
+        &self.point
+    }
+
+    pub fn expected_evaluation(&self) -> F {
+        self.expected_evaluation
+    }
+
+    // Checks an evaluation of f at the point obtained elsewhere, e.g. from a commitment opening
+    pub fn check(&self, evaluation: F) -> Result<(), SumcheckError> {
+        if evaluation != self.expected_evaluation {
+            return Err(SumcheckError::FinalEvaluationMismatch);
+        }
+        Ok(())
+    }
+
+    // Evaluates f at the point itself
+    pub fn verify<P: SumcheckPolynomial<F>>(&self, poly: &P) -> Result<(), SumcheckError> {
+        self.check(poly.evaluate(&self.point)?)
+    }
+}
+
+// The round polynomials g_1, ..., g_n of a non-interactive proof
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SumcheckProof<F: PrimeField> {
+    round_polynomials: Vec<Polynomials<F>>,
+}
+
+impl<F: PrimeField> SumcheckProof<F> {
+    pub fn new(round_polynomials: Vec<Polynomials<F>>) -> Self {
+        SumcheckProof { round_polynomials }
+    }
+
+    pub fn round_polynomials(&self) -> &Vec<Polynomials<F>> {
+        &self.round_polynomials
+    }
+}
+
+// Binds the statement before the first round so the challenges depend on it
+fn append_statement<F: PrimeField>(transcript: &mut Transcript, variables: usize, degree: usize, claimed_sum: &F) {
+    transcript.append_bytes(b"variables", &(variables as u64).to_le_bytes());
+    transcript.append_bytes(b"degree", &(degree as u64).to_le_bytes());
+    transcript.append_field(b"claimed sum", claimed_sum);
+}
+
+// Non-interactive proof that `claimed_sum` is the sum of `poly` over the hypercube.
+// The verifier takes poly.degree() as the degree bound.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(variables = poly.variables())))]
+pub fn prove<F: PrimeField, P: SumcheckPolynomial<F>>(
+    poly: &P,
+    claimed_sum: F,
+    transcript: &mut Transcript,
+) -> Result<SumcheckProof<F>, SumcheckError> {
+    let mut prover = Prover::new(poly.clone(), claimed_sum)?;
+    append_statement(transcript, poly.variables(), poly.degree(), &claimed_sum);
+
+    let mut round_polynomials = Vec::with_capacity(poly.variables());
+    while prover.rounds_left() > 0 {
+        let round_polynomial = prover.round_polynomial()?;
+        transcript.append_polynomial(b"round polynomial", &round_polynomial);
+        prover.receive_challenge(transcript.challenge(b"round challenge"))?;
+        round_polynomials.push(round_polynomial);
+    }
+
+    Ok(SumcheckProof::new(round_polynomials))
+}
+
+// Replays the transcript of `prove` and checks every round.
+// The returned subclaim still has to be checked against f.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(variables = variables, degree = degree)))]
+pub fn verify<F: PrimeField>(
+    variables: usize,
+    degree: usize,
+    claimed_sum: F,
+    proof: &SumcheckProof<F>,
+    transcript: &mut Transcript,
+) -> Result<Subclaim<F>, SumcheckError> {
+    if proof.round_polynomials.len() != variables {
+        return Err(SumcheckError::RoundCountMismatch {
+            expected: variables,
+            found: proof.round_polynomials.len(),
+        });
+    }
+    append_statement(transcript, variables, degree, &claimed_sum);
+
+    let mut verifier = Verifier::new(variables, degree, claimed_sum);
+    for round_polynomial in proof.round_polynomials.iter() {
+        transcript.append_polynomial(b"round polynomial", round_polynomial);
+        verifier.verify_round_with_challenge(round_polynomial, transcript.challenge(b"round challenge"))?;
+    }
+
+    verifier.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fr;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        multilinear::{
+            dense::DenseMultilinear,
+            mocks::{eq_table, multilinear_polya, multilinear_polyb, random_table},
+            virtual_polynomial::VirtualPolynomial,
+        },
+        univariate::types::PolynomialTrait,
+    };
+
+    #[test]
+    fn should_prove_and_verify_sums() {
+        let polys = [
+            DenseMultilinear::from(&multilinear_polya::<Fr>()),
+            DenseMultilinear::from(&multilinear_polyb::<Fr>()),
+            random_table(10, &mut StdRng::seed_from_u64(3)),
+        ];
+
+        for poly in polys {
+            let sum = poly.sum_over_hypercube();
+            let proof = prove(&poly, sum, &mut Transcript::new(b"test")).unwrap();
+            assert_eq!(proof.round_polynomials().len(), poly.variables());
+
+            let subclaim = verify(poly.variables(), 1, sum, &proof, &mut Transcript::new(b"test")).unwrap();
+            assert!(subclaim.verify(&poly).is_ok());
+            assert_eq!(subclaim.check(poly.evaluate(subclaim.point()).unwrap()), Ok(()));
+        }
+    }
+
+    #[test]
+    fn should_prove_sums_of_sparse_polynomials() {
+        for poly in [multilinear_polya::<Fr>(), multilinear_polyb::<Fr>()] {
+            let table = DenseMultilinear::from(&poly);
+            let sum = table.sum_over_hypercube();
+            assert_eq!(SumcheckPolynomial::sum_over_hypercube(&poly), sum);
+
+            // The terms give the same rounds as the table, so the same proof
+            let proof = prove(&poly, sum, &mut Transcript::new(b"test")).unwrap();
+            assert_eq!(proof, prove(&table, sum, &mut Transcript::new(b"test")).unwrap());
+
+            let subclaim = verify(poly.variables(), 1, sum, &proof, &mut Transcript::new(b"test")).unwrap();
+            assert!(subclaim.verify(&poly).is_ok());
+        }
+    }
+
+    #[test]
+    fn should_reject_a_cheating_prover() {
+        let poly = random_table::<Fr, _>(4, &mut StdRng::seed_from_u64(5));
+        let sum = poly.sum_over_hypercube();
+        let proof = prove(&poly, sum, &mut Transcript::new(b"test")).unwrap();
+
+        // The honest prover refuses a false claim
+        assert_eq!(prove(&poly, sum + Fr::from(1), &mut Transcript::new(b"test")), Err(SumcheckError::WrongClaim));
+
+        // The honest proof does not carry over to a false claim
+        assert_eq!(
+            verify(4, 1, sum + Fr::from(1), &proof, &mut Transcript::new(b"test")),
+            Err(SumcheckError::RoundSumMismatch { round: 0 })
+        );
+
+        // A tampered round polynomial breaks the next round or the final evaluation
+        for round in 0..4 {
+            let mut round_polynomials = proof.round_polynomials().clone();
+            round_polynomials[round] = &round_polynomials[round] + &Polynomials::new(vec![Fr::from(0), Fr::from(1)]).unwrap();
+            let forged = SumcheckProof::new(round_polynomials);
+            assert!(verify(4, 1, sum, &forged, &mut Transcript::new(b"test")).and_then(|subclaim| subclaim.verify(&poly)).is_err());
+        }
+
+        // The proof of another polynomial with the same sum fails the final evaluation
+        let other = DenseMultilinear::new(
+            poly.evaluations().iter().rev().copied().collect()
+        ).unwrap();
+        let subclaim = verify(4, 1, sum, &prove(&other, sum, &mut Transcript::new(b"test")).unwrap(), &mut Transcript::new(b"test")).unwrap();
+        assert_eq!(subclaim.verify(&poly), Err(SumcheckError::FinalEvaluationMismatch));
+    }
+
+    #[test]
+    fn should_bind_the_challenges_to_the_transcript() {
+        let poly = random_table::<Fr, _>(3, &mut StdRng::seed_from_u64(9));
+        let sum = poly.sum_over_hypercube();
+        let proof = prove(&poly, sum, &mut Transcript::new(b"test")).unwrap();
+
+        // Verifying under another label draws other challenges,
+        // so the second round no longer answers the claim g_1(r_1)
+        assert_eq!(
+            verify(3, 1, sum, &proof, &mut Transcript::new(b"other")),
+            Err(SumcheckError::RoundSumMismatch { round: 1 })
+        );
+
+        // Each round polynomial is linear and sums to the claim it answers
+        let first = &proof.round_polynomials()[0];
+        assert_eq!(first.evaluate(Fr::from(0)) + first.evaluate(Fr::from(1)), sum);
+        assert!(first.degree().is_none_or(|degree| degree <= 1));
+
+        assert_eq!(
+            verify(4, 1, sum, &proof, &mut Transcript::new(b"test")),
+            Err(SumcheckError::RoundCountMismatch { expected: 4, found: 3 })
+        );
+    }
+
+    #[test]
+    fn should_prove_sums_of_products() {
+        let mut rng = StdRng::seed_from_u64(21);
+        let r: Vec<Fr> = (0..5).map(|_| Fr::from(rng.random::<u64>())).collect();
+        let (a, b) = (random_table(5, &mut rng), random_table(5, &mut rng));
+        // 2 eq(r, x) A B - A + 7
+        let poly = VirtualPolynomial::from_terms(
+            5,
+            vec![(Fr::from(2), vec![eq_table(&r), a.clone(), b]), (Fr::from(-1), vec![a]), (Fr::from(7), vec![])],
+        )
+        .unwrap();
+        let sum = poly.sum_over_hypercube();
+
+        let proof = prove(&poly, sum, &mut Transcript::new(b"test")).unwrap();
+        assert!(proof.round_polynomials().iter().all(|round| round.degree().is_some_and(|degree| degree <= 3)));
+        let subclaim = verify(5, 3, sum, &proof, &mut Transcript::new(b"test")).unwrap();
+        assert!(subclaim.verify(&poly).is_ok());
+
+        // The degree bound of the verifier must cover the products
+        assert!(matches!(
+            verify(5, 1, sum, &proof, &mut Transcript::new(b"test")),
+            Err(SumcheckError::DegreeTooHigh { round: 0, .. })
+        ));
+
+        // Shifting a round polynomial by a random multiple of X(X - 1) keeps g(0) + g(1)
+        // but moves g(r), so the final evaluation no longer matches
+        let mut round_polynomials = proof.round_polynomials().clone();
+        let shift = Polynomials::new(vec![Fr::from(0), -Fr::from(1), Fr::from(1)]).unwrap();
+        round_polynomials[4] = &round_polynomials[4] + &shift.scalar_mul(Fr::from(rng.random::<u64>()));
+        let forged = verify(5, 3, sum, &SumcheckProof::new(round_polynomials), &mut Transcript::new(b"test")).unwrap();
+        assert_eq!(forged.verify(&poly), Err(SumcheckError::FinalEvaluationMismatch));
+    }
+}
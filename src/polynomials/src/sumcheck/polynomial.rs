@@ -0,0 +1,123 @@
+use ark_ff::PrimeField;
+
+use crate::{
+    error::PolynomialError,
+    multilinear::{dense::DenseMultilinear, virtual_polynomial::VirtualPolynomial, MultiLinearPolynomial},
+    univariate::Polynomials,
+};
+
+// What the prover needs from f: the round polynomial for the first free variable,
+// and fixing that variable once the challenge is known
+pub trait SumcheckPolynomial<F: PrimeField>: Clone {
+    fn variables(&self) -> usize;
+
+    // Degree of f in each variable, bounds the degree of every round polynomial
+    fn degree(&self) -> usize;
+
+    fn sum_over_hypercube(&self) -> F;
+
+    // g(X) = sum of f(X, b) over b in {0,1}^(n-1), needs at least one variable
+    fn round_polynomial(&self) -> Polynomials<F>;
+
+    fn fix_first_variable(&self, value: F) -> Self;
+
+    fn evaluate(&self, point: &[F]) -> Result<F, PolynomialError>;
+}
+
+impl<F: PrimeField> SumcheckPolynomial<F> for DenseMultilinear<F> {
+    fn variables(&self) -> usize {
+        self.variables()
+    }
+
+    fn degree(&self) -> usize {
+        1
+    }
+
+    fn sum_over_hypercube(&self) -> F {
+        self.sum_over_hypercube()
+    }
+
+    // f is linear in X, so g(X) = g(0) + (g(1) - g(0)) X with g(0) and g(1)
+    // the sums of the two halves of the table
+    fn round_polynomial(&self) -> Polynomials<F> {
+        let (low, high) = self.evaluations().split_at(self.evaluations().len() / 2);
+        let at_zero: F = low.iter().sum();
+        let at_one: F = high.iter().sum();
+        Polynomials::from_coefficients(vec![at_zero, at_one - at_zero])
+    }
+
+    fn fix_first_variable(&self, value: F) -> Self {
+        self.fix_variables(&[value]).expect("The polynomial has a variable left")
+    }
+
+    fn evaluate(&self, point: &[F]) -> Result<F, PolynomialError> {
+        self.evaluate(point)
+    }
+}
+
+// Works on the terms directly, so a sparse f never expands to its 2^n table.
+// Summing a monomial over the free variables it does not contain doubles it once per variable.
+impl<F: PrimeField> SumcheckPolynomial<F> for MultiLinearPolynomial<F> {
+    fn variables(&self) -> usize {
+        self.variables()
+    }
+
+    fn degree(&self) -> usize {
+        1
+    }
+
+    // Each term c * prod x_i sums to c * 2^(n - |term|)
+    fn sum_over_hypercube(&self) -> F {
+        self.coefficients()
+            .iter()
+            .map(|(term, coefficient)| *coefficient * F::from(2u64).pow([(self.variables() - term.count_ones() as usize) as u64]))
+            .sum()
+    }
+
+    // A term with the first variable adds c * 2^(n - |term|) X to g, the others add c * 2^(n - 1 - |term|)
+    fn round_polynomial(&self) -> Polynomials<F> {
+        let first = 1usize.checked_shl((self.variables() - 1) as u32).unwrap_or(0);
+        let mut coefficients = vec![F::zero(); 2];
+        for (term, coefficient) in self.coefficients() {
+            let free = (self.variables() - 1 - (term & !first).count_ones() as usize) as u64;
+            coefficients[usize::from(term & first != 0)] += *coefficient * F::from(2u64).pow([free]);
+        }
+        Polynomials::from_coefficients(coefficients)
+    }
+
+    // Once fixed the first variable is in no term, so the terms fit in one variable less
+    fn fix_first_variable(&self, value: F) -> Self {
+        let fixed = self.partial_evaluate(0, value);
+        MultiLinearPolynomial::new(self.variables() - 1, fixed.coefficients().clone())
+    }
+
+    fn evaluate(&self, point: &[F]) -> Result<F, PolynomialError> {
+        self.try_evaluate(point)
+    }
+}
+
+impl<F: PrimeField> SumcheckPolynomial<F> for VirtualPolynomial<F> {
+    fn variables(&self) -> usize {
+        self.variables()
+    }
+
+    fn degree(&self) -> usize {
+        self.degree()
+    }
+
+    fn sum_over_hypercube(&self) -> F {
+        self.sum_over_hypercube()
+    }
+
+    fn round_polynomial(&self) -> Polynomials<F> {
+        self.round_polynomial()
+    }
+
+    fn fix_first_variable(&self, value: F) -> Self {
+        self.fix_variables(&[value]).expect("The polynomial has a variable left")
+    }
+
+    fn evaluate(&self, point: &[F]) -> Result<F, PolynomialError> {
+        self.evaluate(point)
+    }
+}
@@ -0,0 +1,105 @@
+use std::marker::PhantomData;
+
+use ark_ff::PrimeField;
+
+use super::{polynomial::SumcheckPolynomial, SumcheckError};
+use crate::{multilinear::dense::DenseMultilinear, univariate::Polynomials};
+
+// Holds f with the variables fixed so far by the challenges
+#[derive(Debug, Clone)]
+pub struct Prover<F: PrimeField, P: SumcheckPolynomial<F> = DenseMultilinear<F>> {
+    poly: P,
+    _field: PhantomData<F>,
+}
+
+impl<F: PrimeField, P: SumcheckPolynomial<F>> Prover<F, P> {
+    // Fails when the claimed sum is not the sum of f over the hypercube,
+    // an honest prover cannot convince the verifier of it
+    pub fn new(poly: P, claimed_sum: F) -> Result<Self, SumcheckError> {
+        if poly.sum_over_hypercube() != claimed_sum {
+            return Err(SumcheckError::WrongClaim);
+        }
+
+        Ok(Prover {
+            poly,
+            _field: PhantomData,
+        })
+    }
+
+    pub fn rounds_left(&self) -> usize {
+        self.poly.variables()
+    }
+
+    // g(X) = sum of f(r_1, ..., r_(i-1), X, b) over b in {0,1}^(n-i)
+    pub fn round_polynomial(&self) -> Result<Polynomials<F>, SumcheckError> {
+        self.check_rounds_left()?;
+        Ok(self.poly.round_polynomial())
+    }
+
+    // Fixes the variable of the current round to the verifier's challenge
+    pub fn receive_challenge(&mut self, challenge: F) -> Result<(), SumcheckError> {
+        self.check_rounds_left()?;
+        self.poly = self.poly.fix_first_variable(challenge);
+        Ok(())
+    }
+
+    fn check_rounds_left(&self) -> Result<(), SumcheckError> {
+        if self.rounds_left() == 0 {
+            return Err(SumcheckError::NoRoundsLeft);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fq;
+
+    use super::*;
+    use crate::{multilinear::virtual_polynomial::VirtualPolynomial, univariate::types::PolynomialTrait};
+
+    #[test]
+    fn should_send_the_partial_sums() {
+        // f(a,b) takes 1, 2, 3, 4 over the hypercube
+        let table = DenseMultilinear::new(vec![Fq::from(1), Fq::from(2), Fq::from(3), Fq::from(4)]).unwrap();
+        let mut prover = Prover::new(table, Fq::from(10)).unwrap();
+
+        // g(0) = 1 + 2, g(1) = 3 + 4
+        let round = prover.round_polynomial().unwrap();
+        assert_eq!(round.evaluate(Fq::from(0)), Fq::from(3));
+        assert_eq!(round.evaluate(Fq::from(1)), Fq::from(7));
+
+        // f(5,b) takes 1 + 5 * 2 = 11 and 2 + 5 * 2 = 12
+        prover.receive_challenge(Fq::from(5)).unwrap();
+        assert_eq!(prover.rounds_left(), 1);
+        let round = prover.round_polynomial().unwrap();
+        assert_eq!(round.evaluate(Fq::from(0)), Fq::from(11));
+        assert_eq!(round.evaluate(Fq::from(1)), Fq::from(12));
+
+        prover.receive_challenge(Fq::from(2)).unwrap();
+        assert_eq!(prover.round_polynomial(), Err(SumcheckError::NoRoundsLeft));
+        assert_eq!(prover.receive_challenge(Fq::from(2)), Err(SumcheckError::NoRoundsLeft));
+    }
+
+    #[test]
+    fn should_prove_products_of_multilinears() {
+        // f(a,b) = A(a,b) * B(a,b) with A = [1, 2, 3, 4] and B = [2, 0, 1, 3]
+        let table_a = DenseMultilinear::new(vec![Fq::from(1), Fq::from(2), Fq::from(3), Fq::from(4)]).unwrap();
+        let table_b = DenseMultilinear::new(vec![Fq::from(2), Fq::from(0), Fq::from(1), Fq::from(3)]).unwrap();
+        let poly = VirtualPolynomial::from_terms(2, vec![(Fq::from(1), vec![table_a, table_b])]).unwrap();
+        let prover = Prover::new(poly, Fq::from(17)).unwrap();
+
+        // g(0) = 1 * 2 + 2 * 0, g(1) = 3 * 1 + 4 * 3, g(2) = 5 * 0 + 6 * 6
+        let round = prover.round_polynomial().unwrap();
+        assert_eq!(round.degree(), Some(2));
+        assert_eq!(round.evaluate(Fq::from(0)), Fq::from(2));
+        assert_eq!(round.evaluate(Fq::from(1)), Fq::from(15));
+        assert_eq!(round.evaluate(Fq::from(2)), Fq::from(36));
+    }
+
+    #[test]
+    fn should_refuse_a_wrong_claim() {
+        let table = DenseMultilinear::new(vec![Fq::from(1), Fq::from(2)]).unwrap();
+        assert!(matches!(Prover::new(table, Fq::from(4)), Err(SumcheckError::WrongClaim)));
+    }
+}
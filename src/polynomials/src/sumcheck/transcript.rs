@@ -0,0 +1,84 @@
+// Fiat-Shamir transcript over SHA3-256.
+// Every message is absorbed with its label and length, and each challenge hashes
+// everything absorbed so far, so the challenges depend on the whole history of the proof.
+
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use sha3::{Digest, Sha3_256};
+
+use crate::univariate::Polynomials;
+
+#[derive(Debug, Clone)]
+pub struct Transcript {
+    hasher: Sha3_256,
+}
+
+impl Transcript {
+    // The label separates the protocols sharing a transcript format
+    pub fn new(label: &[u8]) -> Self {
+        let mut transcript = Transcript {
+            hasher: Sha3_256::new(),
+        };
+        transcript.append_bytes(b"protocol", label);
+        transcript
+    }
+
+    pub fn append_bytes(&mut self, label: &[u8], bytes: &[u8]) {
+        self.hasher.update((label.len() as u64).to_le_bytes());
+        self.hasher.update(label);
+        self.hasher.update((bytes.len() as u64).to_le_bytes());
+        self.hasher.update(bytes);
+    }
+
+    pub fn append_field<F: PrimeField>(&mut self, label: &[u8], value: &F) {
+        let mut bytes = vec![];
+        value.serialize_compressed(&mut bytes).expect("Writing to a vector never fails");
+        self.append_bytes(label, &bytes);
+    }
+
+    pub fn append_polynomial<F: PrimeField>(&mut self, label: &[u8], poly: &Polynomials<F>) {
+        let mut bytes = vec![];
+        poly.serialize_compressed(&mut bytes).expect("Writing to a vector never fails");
+        self.append_bytes(label, &bytes);
+    }
+
+    // 512 bits reduced modulo p, so the challenge is close to uniform.
+    // The output is absorbed back, two calls in a row give different challenges.
+    pub fn challenge<F: PrimeField>(&mut self, label: &[u8]) -> F {
+        self.append_bytes(b"challenge", label);
+
+        let mut wide = self.hasher.clone().chain_update([0u8]).finalize().to_vec();
+        wide.extend(self.hasher.clone().chain_update([1u8]).finalize());
+        self.hasher.update(&wide);
+
+        F::from_le_bytes_mod_order(&wide)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fr;
+
+    use super::*;
+
+    #[test]
+    fn should_derive_challenges_from_the_history() {
+        let mut transcript_a = Transcript::new(b"test");
+        let mut transcript_b = Transcript::new(b"test");
+        transcript_a.append_field(b"value", &Fr::from(5));
+        transcript_b.append_field(b"value", &Fr::from(5));
+
+        let first: Fr = transcript_a.challenge(b"r");
+        assert_eq!(first, transcript_b.challenge::<Fr>(b"r"));
+        assert_ne!(first, transcript_a.challenge::<Fr>(b"r"));
+
+        // A different message or protocol label changes the challenge
+        let mut other = Transcript::new(b"test");
+        other.append_field(b"value", &Fr::from(6));
+        assert_ne!(first, other.challenge::<Fr>(b"r"));
+
+        let mut other = Transcript::new(b"other");
+        other.append_field(b"value", &Fr::from(5));
+        assert_ne!(first, other.challenge::<Fr>(b"r"));
+    }
+}
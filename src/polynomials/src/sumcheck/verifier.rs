@@ -0,0 +1,162 @@
+use ark_ff::PrimeField;
+use rand::Rng;
+
+use super::{Subclaim, SumcheckError};
+use crate::univariate::{types::PolynomialTrait, Polynomials};
+
+// Tracks the running claim, starting from the claimed sum and
+// replaced by g_i(r_i) after every round
+#[derive(Debug, Clone)]
+pub struct Verifier<F: PrimeField> {
+    variables: usize,
+    // Bound on the degree of every round polynomial
+    degree: usize,
+    claim: F,
+    challenges: Vec<F>,
+}
+
+impl<F: PrimeField> Verifier<F> {
+    pub fn new(variables: usize, degree: usize, claimed_sum: F) -> Self {
+        Verifier {
+            variables,
+            degree,
+            claim: claimed_sum,
+            challenges: Vec::with_capacity(variables),
+        }
+    }
+
+    // Checks g_i against the running claim and samples the challenge r_i,
+    // 512 random bits reduced modulo p so it is close to uniform over the field
+    pub fn verify_round<R: Rng>(&mut self, round_polynomial: &Polynomials<F>, rng: &mut R) -> Result<F, SumcheckError> {
+        let mut wide = [0u8; 64];
+        rng.fill(&mut wide);
+        let challenge = F::from_le_bytes_mod_order(&wide);
+        self.verify_round_with_challenge(round_polynomial, challenge)?;
+        Ok(challenge)
+    }
+
+    // Same as `verify_round` with the challenge given, e.g. drawn from a transcript
+    pub fn verify_round_with_challenge(&mut self, round_polynomial: &Polynomials<F>, challenge: F) -> Result<(), SumcheckError> {
+        let round = self.challenges.len();
+        if round == self.variables {
+            return Err(SumcheckError::RoundCountMismatch {
+                expected: self.variables,
+                found: round + 1,
+            });
+        }
+        if let Some(degree) = round_polynomial.degree().filter(|degree| *degree > self.degree) {
+            return Err(SumcheckError::DegreeTooHigh {
+                round,
+                degree,
+                bound: self.degree,
+            });
+        }
+        // g_i(0) + g_i(1) must be the claim left by the previous round
+        if round_polynomial.evaluate(F::zero()) + round_polynomial.evaluate(F::one()) != self.claim {
+            return Err(SumcheckError::RoundSumMismatch { round });
+        }
+
+        self.claim = round_polynomial.evaluate(challenge);
+        self.challenges.push(challenge);
+        Ok(())
+    }
+
+    // After the last round the sum reduces to the single evaluation f(r_1, ..., r_n)
+    pub fn finish(self) -> Result<Subclaim<F>, SumcheckError> {
+        if self.challenges.len() != self.variables {
+            return Err(SumcheckError::RoundCountMismatch {
+                expected: self.variables,
+                found: self.challenges.len(),
+            });
+        }
+
+        Ok(Subclaim {
+            point: self.challenges,
+            expected_evaluation: self.claim,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fq;
+    use ark_ff::Field;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::{
+        multilinear::{dense::DenseMultilinear, mocks::multilinear_polya},
+        sumcheck::prover::Prover,
+    };
+
+    #[test]
+    fn should_accept_an_honest_prover_interactively() {
+        // f(a,b,c) = 2abc + 2ab + 3bc + 4 sums to 44
+        let table = DenseMultilinear::from(&multilinear_polya::<Fq>());
+        let mut prover = Prover::new(table.clone(), Fq::from(44)).unwrap();
+        let mut verifier = Verifier::new(3, 1, Fq::from(44));
+        let mut rng = StdRng::seed_from_u64(7);
+
+        while prover.rounds_left() > 0 {
+            let round_polynomial = prover.round_polynomial().unwrap();
+            let challenge = verifier.verify_round(&round_polynomial, &mut rng).unwrap();
+            prover.receive_challenge(challenge).unwrap();
+        }
+
+        let subclaim = verifier.finish().unwrap();
+        assert_eq!(subclaim.point().len(), 3);
+        assert!(subclaim.verify(&table).is_ok());
+    }
+
+    #[test]
+    fn should_reject_a_cheating_prover_interactively() {
+        // The true sum is 44, the prover claims 45 and shifts every round polynomial
+        // by a constant so that g_i(0) + g_i(1) matches the running claim
+        let table = DenseMultilinear::from(&multilinear_polya::<Fq>());
+        let mut honest = Prover::new(table.clone(), Fq::from(44)).unwrap();
+        let mut verifier = Verifier::new(3, 1, Fq::from(45));
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let mut claim = Fq::from(45);
+        let two_inv = Fq::from(2).inverse().unwrap();
+        while honest.rounds_left() > 0 {
+            let round_polynomial = honest.round_polynomial().unwrap();
+            let actual = round_polynomial.evaluate(Fq::from(0)) + round_polynomial.evaluate(Fq::from(1));
+            let shift = Polynomials::new(vec![(claim - actual) * two_inv]).unwrap();
+            let forged = &round_polynomial + &shift;
+
+            let challenge = verifier.verify_round(&forged, &mut rng).unwrap();
+            claim = forged.evaluate(challenge);
+            honest.receive_challenge(challenge).unwrap();
+        }
+
+        // Every round passes, the lie surfaces at the final evaluation
+        let subclaim = verifier.finish().unwrap();
+        assert!(matches!(subclaim.verify(&table), Err(SumcheckError::FinalEvaluationMismatch)));
+    }
+
+    #[test]
+    fn should_reject_malformed_rounds() {
+        let mut verifier = Verifier::new(1, 1, Fq::from(10));
+        // x^3 + 4x + 3 sums to 11 but is above the degree bound
+        let cubic = Polynomials::new(vec![Fq::from(3), Fq::from(4), Fq::from(0), Fq::from(1)]).unwrap();
+        assert!(matches!(
+            verifier.verify_round_with_challenge(&cubic, Fq::from(1)),
+            Err(SumcheckError::DegreeTooHigh { round: 0, degree: 3, bound: 1 })
+        ));
+
+        let wrong_sum = Polynomials::new(vec![Fq::from(3), Fq::from(5)]).unwrap();
+        assert!(matches!(
+            verifier.verify_round_with_challenge(&wrong_sum, Fq::from(1)),
+            Err(SumcheckError::RoundSumMismatch { round: 0 })
+        ));
+
+        let honest = Polynomials::new(vec![Fq::from(3), Fq::from(4)]).unwrap();
+        verifier.verify_round_with_challenge(&honest, Fq::from(1)).unwrap();
+        assert!(matches!(
+            verifier.verify_round_with_challenge(&honest, Fq::from(1)),
+            Err(SumcheckError::RoundCountMismatch { expected: 1, found: 2 })
+        ));
+        assert!(Verifier::new(2, 1, Fq::from(10)).finish().is_err());
+    }
+}
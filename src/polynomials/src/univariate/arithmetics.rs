@@ -1,8 +1,11 @@
-use std::ops::{Add, Mul};
+use std::{
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
 
-use ark_ff::{PrimeField, Zero};
+use ark_ff::PrimeField;
 
-use super::Polynomials;
+use super::{fft::ntt_mul, parallel::collect_indexed, Polynomials};
 
 impl<F: PrimeField> Add for &Polynomials<F> {
     type Output = Polynomials<F>;
@@ -14,18 +17,43 @@ impl<F: PrimeField> Add for &Polynomials<F> {
         } else {
             (other.coefficients(), self.coefficients())
         };
-        let mut coefficients = Vec::with_capacity(higher_degree.len());
-
-        // Use the index also.
-        for (index, value) in lower_degree.iter().enumerate() {
-            // add the ld[i] to the hd[i]
-            coefficients.push(*value + higher_degree[index])
-        }
+        // add the ld[i] to the hd[i]
+        let mut coefficients = collect_indexed(lower_degree.len(), |i| lower_degree[i] + higher_degree[i]);
         // copy the rest of the coefficients in higher_degree polynomial
         coefficients.extend_from_slice(&higher_degree[lower_degree.len()..]);
 
         // Create a new polynomial with the coefficients and the degree of the higher degree polynomial
-        Polynomials::new(coefficients).unwrap()
+        Polynomials::from_coefficients(coefficients)
+    }
+}
+
+impl<F: PrimeField> Sub for &Polynomials<F> {
+    type Output = Polynomials<F>;
+
+    fn sub(self, other: &Polynomials<F>) -> Polynomials<F> {
+        let (a, b) = (self.coefficients(), other.coefficients());
+        let zero = F::zero();
+
+        // subtract every coefficient of other at the same power
+        let coefficients = collect_indexed(a.len().max(b.len()), |i| *a.get(i).unwrap_or(&zero) - b.get(i).unwrap_or(&zero));
+
+        Polynomials::from_coefficients(coefficients)
+    }
+}
+
+impl<F: PrimeField> Neg for &Polynomials<F> {
+    type Output = Polynomials<F>;
+
+    fn neg(self) -> Polynomials<F> {
+        Polynomials::from_coefficients(self.coefficients().iter().map(|x| -*x).collect())
+    }
+}
+
+impl<F: PrimeField> Neg for Polynomials<F> {
+    type Output = Polynomials<F>;
+
+    fn neg(self) -> Polynomials<F> {
+        -&self
     }
 }
 
@@ -33,27 +61,151 @@ impl<F: PrimeField> Mul for &Polynomials<F> {
     type Output = Polynomials<F>;
 
     fn mul(self, other: &Polynomials<F>) -> Polynomials<F> {
-        let output_degree = self.coefficients().len() + other.coefficients().len() - 2;
+        Polynomials::from_coefficients(mul_coefficients(self.coefficients(), other.coefficients()))
+    }
+}
+
+impl<F: PrimeField> Div for &Polynomials<F> {
+    type Output = Polynomials<F>;
+
+    fn div(self, other: &Polynomials<F>) -> Polynomials<F> {
+        self.try_div(other).expect("Cannot divide by the zero polynomial")
+    }
+}
+
+impl<F: PrimeField> Rem for &Polynomials<F> {
+    type Output = Polynomials<F>;
+
+    fn rem(self, other: &Polynomials<F>) -> Polynomials<F> {
+        self.try_rem(other).expect("Cannot divide by the zero polynomial")
+    }
+}
 
-        let mut coefficients = vec![Zero::zero(); output_degree + 1];
-        for (i, coeff1) in self.coefficients().iter().enumerate() {
-            for (j, coeff2) in other.coefficients().iter().enumerate() {
-                coefficients[i + j] = coefficients[i + j] + &(*coeff1 * coeff2);
+forward_binop!(Polynomials, Polynomials, Polynomials, Add, add);
+forward_binop!(Polynomials, Polynomials, Polynomials, Sub, sub);
+forward_binop!(Polynomials, Polynomials, Polynomials, Mul, mul);
+forward_binop!(Polynomials, Polynomials, Polynomials, Div, div);
+forward_binop!(Polynomials, Polynomials, Polynomials, Rem, rem);
+
+// `a op= b` for both owned and borrowed right hand sides
+macro_rules! forward_assign_op {
+    ($trait:ident, $method:ident, $op:ident) => {
+        impl<F: PrimeField> $trait<&Polynomials<F>> for Polynomials<F> {
+            fn $method(&mut self, other: &Polynomials<F>) {
+                *self = (&*self).$op(other);
             }
         }
 
-        Polynomials::new(coefficients).unwrap()
+        impl<F: PrimeField> $trait<Polynomials<F>> for Polynomials<F> {
+            fn $method(&mut self, other: Polynomials<F>) {
+                *self = (&*self).$op(&other);
+            }
+        }
+    };
+}
+
+forward_assign_op!(AddAssign, add_assign, add);
+forward_assign_op!(SubAssign, sub_assign, sub);
+forward_assign_op!(MulAssign, mul_assign, mul);
+
+// Mixing with a scalar, `poly + c` only touches the constant term
+impl<F: PrimeField> Add<F> for &Polynomials<F> {
+    type Output = Polynomials<F>;
+
+    fn add(self, scalar: F) -> Polynomials<F> {
+        let mut coefficients = self.coefficients().clone();
+        coefficients[0] += scalar;
+        Polynomials::from_coefficients(coefficients)
+    }
+}
+
+impl<F: PrimeField> Sub<F> for &Polynomials<F> {
+    type Output = Polynomials<F>;
+
+    fn sub(self, scalar: F) -> Polynomials<F> {
+        self + (-scalar)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &Polynomials<F> {
+    type Output = Polynomials<F>;
+
+    fn mul(self, scalar: F) -> Polynomials<F> {
+        self.scalar_mul(scalar)
+    }
+}
+
+macro_rules! forward_scalar_op {
+    ($trait:ident, $method:ident) => {
+        impl<F: PrimeField> $trait<F> for Polynomials<F> {
+            type Output = Polynomials<F>;
+
+            fn $method(self, scalar: F) -> Polynomials<F> {
+                (&self).$method(scalar)
+            }
+        }
+    };
+}
+
+forward_scalar_op!(Add, add);
+forward_scalar_op!(Sub, sub);
+forward_scalar_op!(Mul, mul);
+
+impl<F: PrimeField> Sum for Polynomials<F> {
+    fn sum<I: Iterator<Item = Polynomials<F>>>(iter: I) -> Self {
+        iter.fold(Polynomials::zero(), |acc, poly| &acc + &poly)
+    }
+}
+
+impl<'a, F: PrimeField> Sum<&'a Polynomials<F>> for Polynomials<F> {
+    fn sum<I: Iterator<Item = &'a Polynomials<F>>>(iter: I) -> Self {
+        iter.fold(Polynomials::zero(), |acc, poly| &acc + poly)
+    }
+}
+
+impl<F: PrimeField> Product for Polynomials<F> {
+    fn product<I: Iterator<Item = Polynomials<F>>>(iter: I) -> Self {
+        iter.fold(Polynomials::from_coefficients(vec![F::one()]), |acc, poly| &acc * &poly)
+    }
+}
+
+impl<'a, F: PrimeField> Product<&'a Polynomials<F>> for Polynomials<F> {
+    fn product<I: Iterator<Item = &'a Polynomials<F>>>(iter: I) -> Self {
+        iter.fold(Polynomials::from_coefficients(vec![F::one()]), |acc, poly| &acc * poly)
+    }
+}
+
+// Large operands over FFT friendly fields go through the NTT,
+// everything else falls back to the schoolbook product.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(lhs = a.len(), rhs = b.len())))]
+pub(crate) fn mul_coefficients<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    match ntt_mul(a, b) {
+        Some(coefficients) => coefficients,
+        None => naive_mul(a, b),
     }
 }
 
+// Schoolbook O(n * m) multiplication of two coefficient vectors,
+// the k-th coefficient is sum_i a_i * b_(k - i) so each one is computed independently
+pub(crate) fn naive_mul<F: PrimeField>(a: &[F], b: &[F]) -> Vec<F> {
+    let output_degree = a.len() + b.len() - 2;
+
+    collect_indexed(output_degree + 1, |k| {
+        let start = k.saturating_sub(b.len() - 1);
+        let end = k.min(a.len() - 1);
+        (start..=end).map(|i| a[i] * b[k - i]).sum()
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use std::vec;
 
-    use ark_ff::AdditiveGroup;
-    use ark_bn254::Fq;
+    use ark_ff::{AdditiveGroup, Zero};
+    use ark_bn254::{Fq, Fr};
 
     use super::*;
+    use crate::univariate::types::PolynomialTrait;
 
     #[test]
     fn should_add_polynomials() {
@@ -120,7 +272,113 @@ mod tests {
         assert_eq!(result.coefficients()[1], Fq::from(63));
         assert_eq!(result.coefficients()[2], Fq::from(-45));
         assert_eq!(result.coefficients()[3], Fq::from(-113));
-        assert_eq!(result.degree(), 8);
+        assert_eq!(result.degree(), Some(8));
+    }
+
+    #[test]
+    fn should_match_naive_product_with_ntt() {
+        // bn254 Fr is FFT friendly, large operands take the NTT path
+        let poly1 = Polynomials::new(
+            (0..300u64).map(|i| Fr::from(i * 7 + 1)).collect()
+        ).unwrap();
+        let poly2 = Polynomials::new(
+            (0..257u64).map(|i| -Fr::from(i * i + 3)).collect()
+        ).unwrap();
+
+        let result = &poly1 * &poly2;
+        assert_eq!(result.coefficients(), &naive_mul(poly1.coefficients(), poly2.coefficients()));
+
+        // bn254 Fq is not, the product falls back to the schoolbook loop
+        let poly1 = Polynomials::new((0..64u64).map(Fq::from).collect()).unwrap();
+        let result = &poly1 * &poly1;
+        assert_eq!(result.coefficients(), &naive_mul(poly1.coefficients(), poly1.coefficients()));
+    }
+
+    #[test]
+    fn should_divide_with_operators() {
+        // x^3 + 9x^2 - 9
+        let poly1 = Polynomials::new(
+            vec![Fq::from(-9), Zero::zero(), Fq::from(9), Fq::from(1)]
+        ).unwrap();
+        // x^2 + 1
+        let poly2 = Polynomials::new(
+            vec![Fq::from(1), Zero::zero(), Fq::from(1)]
+        ).unwrap();
+
+        // x^3 + 9x^2 - 9 = (x + 9)(x^2 + 1) - x - 18
+        assert_eq!((&poly1 / &poly2).coefficients(), &vec![Fq::from(9), Fq::from(1)]);
+        assert_eq!((&poly1 % &poly2).coefficients(), &vec![Fq::from(-18), Fq::from(-1)]);
+    }
+
+    #[test]
+    fn should_subtract_and_negate_polynomials() {
+        // 3x^5 + 5x^3 - 7x - 5
+        let poly1 = Polynomials::new(
+            vec![Fq::from(-5), Fq::from(-7), Zero::zero(), Fq::from(5), Zero::zero(), Fq::from(3)],
+        ).unwrap();
+        // x^3 + 9x^2 - 9
+        let poly2 = Polynomials::new(
+            vec![Fq::from(-9), Zero::zero(), Fq::from(9), Fq::from(1)]
+        ).unwrap();
+
+        // 3x^5 + 4x^3 - 9x^2 - 7x + 4
+        let result = &poly1 - &poly2;
+        assert_eq!(result.coefficients(), &vec![Fq::from(4), Fq::from(-7), Fq::from(-9), Fq::from(4), Zero::zero(), Fq::from(3)]);
+
+        // -x^3 - 9x^2 + 9 - (3x^5 + 5x^3 - 7x - 5)
+        let result = &poly2 - &poly1;
+        assert_eq!(result.coefficients(), (-&(&poly1 - &poly2)).coefficients());
+        assert_eq!((-poly2).coefficients(), &vec![Fq::from(9), Zero::zero(), Fq::from(-9), Fq::from(-1)]);
+    }
+
+    #[test]
+    fn should_mix_owned_and_borrowed_operands() {
+        // 3x + 2
+        let poly1 = Polynomials::new(vec![Fq::from(2), Fq::from(3)]).unwrap();
+        // 2x + 4
+        let poly2 = Polynomials::new(vec![Fq::from(4), Fq::from(2)]).unwrap();
+
+        let expected = (&poly1 * &poly2).coefficients().clone();
+        assert_eq!((poly1.clone() * poly2.clone()).coefficients(), &expected);
+        assert_eq!((poly1.clone() * &poly2).coefficients(), &expected);
+        assert_eq!((&poly1 * poly2.clone()).coefficients(), &expected);
+
+        // 5x + 6
+        let mut result = poly1.clone();
+        result += &poly2;
+        assert_eq!(result.coefficients(), &vec![Fq::from(6), Fq::from(5)]);
+        // 3x + 2
+        result -= poly2.clone();
+        assert_eq!(result.coefficients(), poly1.coefficients());
+        // 6x^2 + 16x + 8
+        result *= &poly2;
+        assert_eq!(result.coefficients(), &expected);
+    }
+
+    #[test]
+    fn should_mix_polynomials_and_scalars() {
+        // 3x + 2
+        let poly = Polynomials::new(vec![Fq::from(2), Fq::from(3)]).unwrap();
+
+        assert_eq!((&poly + Fq::from(5)).coefficients(), &vec![Fq::from(7), Fq::from(3)]);
+        assert_eq!((&poly - Fq::from(5)).coefficients(), &vec![Fq::from(-3), Fq::from(3)]);
+        assert_eq!((poly * Fq::from(2)).coefficients(), &vec![Fq::from(4), Fq::from(6)]);
+    }
+
+    #[test]
+    fn should_sum_and_multiply_iterators() {
+        // x + 1, x + 2, x + 3
+        let polys: Vec<Polynomials<Fq>> = (1..=3u64)
+            .map(|i| Polynomials::new(vec![Fq::from(i), Fq::from(1)]).unwrap())
+            .collect();
+
+        // 3x + 6
+        let sum: Polynomials<Fq> = polys.iter().sum();
+        assert_eq!(sum.coefficients(), &vec![Fq::from(6), Fq::from(3)]);
+
+        // x^3 + 6x^2 + 11x + 6
+        let product: Polynomials<Fq> = polys.into_iter().product();
+        assert_eq!(product.coefficients(), &vec![Fq::from(6), Fq::from(11), Fq::from(6), Fq::from(1)]);
     }
 
     #[test]
@@ -131,7 +389,7 @@ mod tests {
         ).unwrap();
 
         assert_eq!(poly1.scalar_mul(Fq::from(4)).coefficients(), &vec![Fq::from(8), Fq::from(12)]);
-        assert_eq!(poly1.scalar_mul(Zero::zero()).coefficients(), vec![Fq::zero(), Fq::ZERO].as_slice());
+        assert_eq!(poly1.scalar_mul(Zero::zero()).coefficients(), vec![Fq::ZERO].as_slice());
         assert_eq!(poly1.scalar_mul(Fq::from(-1)).coefficients(), vec![Fq::from(-2), Fq::from(-3)].as_slice());
 
         // 8x^2 + 14x + 8
@@ -141,4 +399,20 @@ mod tests {
         assert_eq!(poly2.scalar_mul(Fq::from(10)).coefficients(), &vec![Fq::from(80), Fq::from(140), Fq::from(80)]);
         assert_eq!(poly2.scalar_mul(Fq::from(4)).coefficients(), &vec![Fq::from(32), Fq::from(56), Fq::from(32)]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn should_match_pointwise_values_on_large_inputs() {
+        // Large enough for the parallel loops with the rayon feature,
+        // Fq has no large roots of unity so the product is schoolbook
+        let a = Polynomials::new((0..5000u64).map(|i| Fq::from(i * i + 3)).collect()).unwrap();
+        let b = Polynomials::new((0..4500u64).map(|i| Fq::from(7 * i + 1)).collect()).unwrap();
+        let c = Polynomials::new(vec![Fq::from(2), Fq::from(-1), Fq::from(5)]).unwrap();
+
+        for x in [Fq::from(0), Fq::from(3), Fq::from(-11)] {
+            assert_eq!((&a + &b).evaluate(x), a.evaluate(x) + b.evaluate(x));
+            assert_eq!((&b - &a).evaluate(x), b.evaluate(x) - a.evaluate(x));
+            assert_eq!((&a * &c).evaluate(x), a.evaluate(x) * c.evaluate(x));
+            assert_eq!(a.scalar_mul(Fq::from(6)).evaluate(x), a.evaluate(x) * Fq::from(6));
+        }
+    }
+}
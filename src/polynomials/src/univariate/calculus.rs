@@ -0,0 +1,146 @@
+// Formal calculus over F[x]. The derivative is defined term by term, (a x^i)' = i a x^(i-1),
+// so it works in any characteristic, while the antiderivative needs to divide by i + 1.
+
+use ark_ff::PrimeField;
+
+use super::{lagrange::LagrangeInterpolator, types::PolynomialTrait, PolynomialError, Polynomials};
+
+impl<F: PrimeField> Polynomials<F> {
+    pub fn derivative(&self) -> Polynomials<F> {
+        self.nth_derivative(1)
+    }
+
+    // The k-th derivative, a_i x^i becomes i (i - 1) ... (i - k + 1) a_i x^(i - k)
+    pub fn nth_derivative(&self, k: usize) -> Polynomials<F> {
+        let coefficients = self
+            .coefficients()
+            .iter()
+            .enumerate()
+            .skip(k)
+            .map(|(power, coefficient)| {
+                let falling_factorial = (power - k + 1..=power).fold(F::one(), |acc, i| acc * F::from(i as u64));
+                falling_factorial * coefficient
+            })
+            .collect();
+
+        Polynomials::from_coefficients(coefficients)
+    }
+
+    // The antiderivative with zero constant term, a_i x^i becomes a_i / (i + 1) x^(i + 1).
+    // Fails when the field characteristic divides one of the new exponents.
+    pub fn integral(&self) -> Result<Polynomials<F>, PolynomialError> {
+        let mut coefficients = vec![F::zero()];
+        for (power, coefficient) in self.coefficients().iter().enumerate() {
+            if coefficient.is_zero() {
+                coefficients.push(F::zero());
+                continue;
+            }
+
+            let exponent_inv = F::from((power + 1) as u64)
+                .inverse()
+                .ok_or(PolynomialError::CharacteristicDividesExponent { exponent: power + 1 })?;
+            coefficients.push(*coefficient * exponent_inv);
+        }
+
+        Ok(Polynomials::from_coefficients(coefficients))
+    }
+
+    // The unique polynomial of degree < 2n matching both values and derivatives
+    // at n points (x, f(x), f'(x)):
+    //     H(x) = sum_i [y_i + (x - x_i)(y'_i - 2 y_i L_i'(x_i))] L_i(x)^2
+    // where L_i is the Lagrange basis polynomial of x_i.
+    pub fn hermite_interpolate(points: Vec<(F, F, F)>) -> Result<Polynomials<F>, PolynomialError> {
+        let xs: Vec<F> = points.iter().map(|(x, _, _)| *x).collect();
+        let interpolator = LagrangeInterpolator::new(xs)?;
+
+        let mut result = Polynomials::zero();
+        let mut unit = vec![F::zero(); points.len()];
+        for (i, (x, y, dy)) in points.iter().enumerate() {
+            unit[i] = F::one();
+            let basis = interpolator.interpolate(&unit)?;
+            unit[i] = F::zero();
+
+            // y_i + (x - x_i)(y'_i - 2 y_i L_i'(x_i))
+            let slope = *dy - F::from(2u64) * y * basis.derivative().evaluate(*x);
+            let factor = Polynomials::from_coefficients(vec![*y - slope * x, slope]);
+
+            result += &factor * &(&basis * &basis);
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fq;
+
+    use super::*;
+    use crate::univariate::mocks::{poly, F17};
+
+    #[test]
+    fn should_differentiate() {
+        // 3x^5 + 5x^3 - 7x - 5
+        let f = poly::<Fq>(&[-5, -7, 0, 5, 0, 3]);
+
+        // 15x^4 + 15x^2 - 7
+        assert_eq!(f.derivative(), poly(&[-7, 0, 15, 0, 15]));
+        // 60x^3 + 30x
+        assert_eq!(f.nth_derivative(2), poly(&[0, 30, 0, 60]));
+        assert_eq!(f.nth_derivative(2), f.derivative().derivative());
+        assert_eq!(f.nth_derivative(5), poly(&[360]));
+        assert!(f.nth_derivative(6).is_zero());
+        assert_eq!(f.nth_derivative(0), f);
+    }
+
+    #[test]
+    fn should_integrate() {
+        // 15x^4 + 15x^2 - 7
+        let f = poly::<Fq>(&[-7, 0, 15, 0, 15]);
+
+        // 3x^5 + 5x^3 - 7x
+        let integral = f.integral().unwrap();
+        assert_eq!(integral, poly(&[0, -7, 0, 5, 0, 3]));
+        assert_eq!(integral.derivative(), f);
+    }
+
+    #[test]
+    fn should_not_integrate_when_the_characteristic_divides_an_exponent() {
+        // x^16 integrates to x^17 / 17, 17 is zero in F17
+        let mut coefficients = vec![F17::from(0u64); 17];
+        coefficients[16] = F17::from(1u64);
+        let f = Polynomials::new(coefficients).unwrap();
+        assert_eq!(f.integral(), Err(PolynomialError::CharacteristicDividesExponent { exponent: 17 }));
+
+        // The derivative of x^17 is 17x^16 = 0
+        let mut coefficients = vec![F17::from(0u64); 18];
+        coefficients[17] = F17::from(1u64);
+        assert!(Polynomials::new(coefficients).unwrap().derivative().is_zero());
+    }
+
+    #[test]
+    fn should_hermite_interpolate() {
+        // f = x^3 - 2x + 1 and f' = 3x^2 - 2 at x = 0 and x = 2
+        let f = poly(&[1, -2, 0, 1]);
+        let df = f.derivative();
+        let points: Vec<(Fq, Fq, Fq)> = [Fq::from(0), Fq::from(2)]
+            .iter()
+            .map(|x| (*x, f.evaluate(*x), df.evaluate(*x)))
+            .collect();
+
+        assert_eq!(Polynomials::hermite_interpolate(points).unwrap(), f);
+
+        // 3 points fit a polynomial of degree 5
+        let f = poly(&[4, -1, 0, 7, 2, -3]);
+        let df = f.derivative();
+        let points: Vec<(Fq, Fq, Fq)> = [Fq::from(-1), Fq::from(3), Fq::from(5)]
+            .iter()
+            .map(|x| (*x, f.evaluate(*x), df.evaluate(*x)))
+            .collect();
+        let hermite = Polynomials::hermite_interpolate(points).unwrap();
+        assert_eq!(hermite, f);
+
+        let duplicated = vec![(Fq::from(1), Fq::from(1), Fq::from(1)), (Fq::from(1), Fq::from(2), Fq::from(3))];
+        assert_eq!(Polynomials::hermite_interpolate(duplicated), Err(PolynomialError::DuplicatePoints));
+    }
+}
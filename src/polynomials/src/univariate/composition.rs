@@ -0,0 +1,135 @@
+// Changes of variable for univariate polynomials:
+// compose f(g(x)), Taylor shift f(x + c) and scaling f(c * x).
+
+use ark_ff::PrimeField;
+
+use super::Polynomials;
+
+// Below this many coefficients composition uses plain Horner nesting,
+// above it the divide-and-conquer split benefits from fast multiplication.
+const COMPOSE_SPLIT_THRESHOLD: usize = 32;
+
+impl<F: PrimeField> Polynomials<F> {
+    // f(g(x))
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(outer = self.coefficients().len(), inner = g.coefficients().len())))]
+    pub fn compose(&self, g: &Polynomials<F>) -> Polynomials<F> {
+        let coefficients = self.coefficients();
+        if coefficients.len() <= COMPOSE_SPLIT_THRESHOLD {
+            return horner_compose(coefficients, g);
+        }
+
+        // powers[j] = g^(2^j), enough to split the full coefficient range
+        let mut powers = vec![g.clone()];
+        while 1 << powers.len() < coefficients.len() {
+            let last = &powers[powers.len() - 1];
+            powers.push(last * last);
+        }
+
+        split_compose(coefficients, &powers)
+    }
+
+    // f(x + c), using the O(n^2) Taylor shift by repeated synthetic division
+    pub fn shift(&self, c: F) -> Polynomials<F> {
+        let mut coefficients = self.coefficients().clone();
+        let n = coefficients.len();
+
+        for i in 0..n {
+            for j in (i..n - 1).rev() {
+                let carry = c * coefficients[j + 1];
+                coefficients[j] += carry;
+            }
+        }
+
+        Polynomials::from_coefficients(coefficients)
+    }
+
+    // f(c * x), the i-th coefficient is multiplied by c^i
+    pub fn scale(&self, c: F) -> Polynomials<F> {
+        let mut power = F::one();
+        let coefficients = self
+            .coefficients()
+            .iter()
+            .map(|coefficient| {
+                let scaled = *coefficient * power;
+                power *= c;
+                scaled
+            })
+            .collect();
+
+        Polynomials::from_coefficients(coefficients)
+    }
+}
+
+// (((a_n * g + a_(n-1)) * g + ...) * g + a_0
+fn horner_compose<F: PrimeField>(coefficients: &[F], g: &Polynomials<F>) -> Polynomials<F> {
+    let mut result = Polynomials::zero();
+    for coefficient in coefficients.iter().rev() {
+        result = &(&result * g) + *coefficient;
+    }
+    result
+}
+
+// f = low + x^half * high, so f(g) = low(g) + g^half * high(g)
+fn split_compose<F: PrimeField>(coefficients: &[F], powers: &[Polynomials<F>]) -> Polynomials<F> {
+    if coefficients.len() <= COMPOSE_SPLIT_THRESHOLD {
+        return horner_compose(coefficients, &powers[0]);
+    }
+
+    let half = coefficients.len().next_power_of_two() / 2;
+    let (low, high) = coefficients.split_at(half);
+
+    let low = split_compose(low, powers);
+    let high = split_compose(high, powers);
+    &low + &(&high * &powers[half.trailing_zeros() as usize])
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::{Fq, Fr};
+
+    use super::*;
+    use crate::univariate::{mocks::poly, types::PolynomialTrait};
+
+    #[test]
+    fn should_compose_polynomials() {
+        // f = x^2 + 1, g = 2x - 3
+        let f = poly::<Fq>(&[1, 0, 1]);
+        let g = poly(&[-3, 2]);
+
+        // (2x - 3)^2 + 1 = 4x^2 - 12x + 10
+        assert_eq!(f.compose(&g), poly(&[10, -12, 4]));
+        // 2(x^2 + 1) - 3 = 2x^2 - 1
+        assert_eq!(g.compose(&f), poly(&[-1, 0, 2]));
+        // Composing with a constant evaluates
+        assert_eq!(f.compose(&poly(&[5])), poly(&[26]));
+    }
+
+    #[test]
+    fn should_compose_large_polynomials() {
+        // Long enough to take the divide-and-conquer path
+        let f = Polynomials::new((0..100u64).map(|i| Fr::from(i * 3 + 1)).collect()).unwrap();
+        let g = Polynomials::new(vec![Fr::from(2), Fr::from(-1), Fr::from(5)]).unwrap();
+
+        let composed = f.compose(&g);
+        assert_eq!(composed.degree(), Some(198));
+        for x in [Fr::from(0), Fr::from(3), Fr::from(-8)] {
+            assert_eq!(composed.evaluate(x), f.evaluate(g.evaluate(x)));
+        }
+    }
+
+    #[test]
+    fn should_shift_and_scale() {
+        // 3x^5 + 5x^3 - 7x - 5
+        let f = poly(&[-5, -7, 0, 5, 0, 3]);
+
+        let shifted = f.shift(Fq::from(2));
+        assert_eq!(shifted, f.compose(&poly(&[2, 1])));
+        for x in [Fq::from(0), Fq::from(4), Fq::from(-9)] {
+            assert_eq!(shifted.evaluate(x), f.evaluate(x + Fq::from(2)));
+        }
+
+        // f(2x) = 96x^5 + 40x^3 - 14x - 5
+        assert_eq!(f.scale(Fq::from(2)), poly(&[-5, -14, 0, 40, 0, 96]));
+        assert_eq!(f.scale(Fq::from(0)), poly(&[-5]));
+    }
+}
@@ -0,0 +1,220 @@
+// Human-readable form of univariate polynomials, e.g. 3x^5 + 5x^3 - 7x - 5.
+// Field elements above (p - 1) / 2 are shown as small negative integers,
+// so -5 prints as -5 instead of p - 5. FromStr accepts the same syntax.
+
+use std::{fmt, str::FromStr};
+
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+
+use super::{sparse::SparsePolynomial, PolynomialError, Polynomials};
+
+// Parsing into the dense form allocates degree + 1 coefficients, larger
+// degrees have to be parsed as a SparsePolynomial
+const MAX_DENSE_DEGREE: usize = 1 << 20;
+
+impl<F: PrimeField> fmt::Display for Polynomials<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let terms = self.coefficients().iter().enumerate().rev().filter(|(_, coefficient)| !coefficient.is_zero());
+        write_terms(f, terms.map(|(exponent, coefficient)| (exponent, *coefficient)))
+    }
+}
+
+impl<F: PrimeField> fmt::Display for SparsePolynomial<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_terms(f, self.terms().iter().rev().copied())
+    }
+}
+
+impl<F: PrimeField> FromStr for Polynomials<F> {
+    type Err = PolynomialError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let sparse = SparsePolynomial::from_str(input)?;
+        if let Some(degree) = sparse.degree().filter(|degree| *degree > MAX_DENSE_DEGREE) {
+            return Err(PolynomialError::ParseError(format!(
+                "degree {} is above the dense limit of {}",
+                degree, MAX_DENSE_DEGREE
+            )));
+        }
+        Ok(sparse.to_dense())
+    }
+}
+
+impl<F: PrimeField> FromStr for SparsePolynomial<F> {
+    type Err = PolynomialError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(SparsePolynomial::new(parse_terms(input)?))
+    }
+}
+
+// Writes the non-zero terms given from the highest power down, "0" when there are none
+fn write_terms<F: PrimeField>(f: &mut fmt::Formatter<'_>, terms: impl Iterator<Item = (usize, F)>) -> fmt::Result {
+    let mut first = true;
+    for (exponent, coefficient) in terms {
+        let negative = coefficient.into_bigint() > F::MODULUS_MINUS_ONE_DIV_TWO;
+        let magnitude = if negative { -coefficient } else { coefficient };
+
+        match (first, negative) {
+            (true, true) => write!(f, "-")?,
+            (true, false) => {}
+            (false, true) => write!(f, " - ")?,
+            (false, false) => write!(f, " + ")?,
+        }
+        first = false;
+
+        // The coefficient 1 is implied on x
+        if exponent == 0 || !magnitude.is_one() {
+            write!(f, "{}", magnitude.into_bigint())?;
+        }
+        match exponent {
+            0 => {}
+            1 => write!(f, "x")?,
+            _ => write!(f, "x^{}", exponent)?,
+        }
+    }
+
+    if first {
+        write!(f, "0")?;
+    }
+    Ok(())
+}
+
+// Splits "3x^5 + 5x^3 - 7x - 5" into signed terms, whitespace is ignored.
+// A term is an integer, x, x^e, cx or c*x^e; repeated exponents are added together.
+fn parse_terms<F: PrimeField>(input: &str) -> Result<Vec<(usize, F)>, PolynomialError> {
+    let compact: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    if compact.is_empty() {
+        return Err(PolynomialError::ParseError("the input is empty".to_string()));
+    }
+
+    let mut terms = vec![];
+    let mut rest = compact.as_str();
+    while !rest.is_empty() {
+        // Only the first term may omit its sign
+        let (negative, unsigned) = if let Some(unsigned) = rest.strip_prefix('-') {
+            (true, unsigned)
+        } else if let Some(unsigned) = rest.strip_prefix('+') {
+            (false, unsigned)
+        } else {
+            (false, rest)
+        };
+
+        let end = unsigned.find(['+', '-']).unwrap_or(unsigned.len());
+        let (exponent, coefficient) = parse_term::<F>(&unsigned[..end])?;
+        terms.push((exponent, if negative { -coefficient } else { coefficient }));
+        rest = &unsigned[end..];
+    }
+
+    Ok(terms)
+}
+
+fn parse_term<F: PrimeField>(term: &str) -> Result<(usize, F), PolynomialError> {
+    if term.is_empty() {
+        return Err(PolynomialError::ParseError("missing term".to_string()));
+    }
+
+    let Some(position) = term.find('x') else {
+        return Ok((0, parse_coefficient(term)?));
+    };
+
+    let coefficient = match &term[..position] {
+        "" => F::one(),
+        prefix => parse_coefficient(prefix.strip_suffix('*').unwrap_or(prefix))?,
+    };
+    let exponent = match &term[position + 1..] {
+        "" => 1,
+        suffix => suffix
+            .strip_prefix('^')
+            .and_then(|exponent| exponent.parse::<usize>().ok())
+            // The number of coefficients up to x^e must fit in a usize
+            .filter(|exponent| exponent.checked_add(1).is_some())
+            .ok_or_else(|| PolynomialError::ParseError(format!("invalid exponent in '{}'", term)))?,
+    };
+
+    Ok((exponent, coefficient))
+}
+
+// Decimal integers of any size, reduced modulo p
+fn parse_coefficient<F: PrimeField>(coefficient: &str) -> Result<F, PolynomialError> {
+    coefficient
+        .parse::<BigUint>()
+        .map(F::from)
+        .map_err(|_| PolynomialError::ParseError(format!("invalid coefficient '{}'", coefficient)))
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fq;
+
+    use super::*;
+    use crate::univariate::mocks::{poly, F17};
+
+    #[test]
+    fn should_display_small_signed_coefficients() {
+        assert_eq!(poly::<Fq>(&[-5, -7, 0, 5, 0, 3]).to_string(), "3x^5 + 5x^3 - 7x - 5");
+        assert_eq!(poly::<Fq>(&[0, 1, -1]).to_string(), "-x^2 + x");
+        assert_eq!(poly::<Fq>(&[1]).to_string(), "1");
+        assert_eq!(poly::<Fq>(&[-1]).to_string(), "-1");
+        assert_eq!(Polynomials::<Fq>::zero().to_string(), "0");
+
+        // 9 is above (17 - 1) / 2 so it prints as -8
+        let small = Polynomials::new(vec![F17::from(9u64), F17::from(8u64)]).unwrap();
+        assert_eq!(small.to_string(), "8x - 8");
+
+        let sparse = SparsePolynomial::from(&poly::<Fq>(&[-1, 0, 0, 0, 2]));
+        assert_eq!(sparse.to_string(), "2x^4 - 1");
+    }
+
+    #[test]
+    fn should_parse_polynomials() {
+        let expected = poly(&[-5, -7, 0, 5, 0, 3]);
+        assert_eq!("3x^5 + 5x^3 - 7x - 5".parse::<Polynomials<Fq>>().unwrap(), expected);
+        assert_eq!("-5-7*x+5*x^3+3*x^5".parse::<Polynomials<Fq>>().unwrap(), expected);
+        assert_eq!("x^5 + 5x^3 - 7x + 2x^5 - 5".parse::<Polynomials<Fq>>().unwrap(), expected);
+
+        assert_eq!("-x^2 + x".parse::<Polynomials<Fq>>().unwrap(), poly(&[0, 1, -1]));
+        assert_eq!("x - x".parse::<Polynomials<Fq>>().unwrap(), Polynomials::zero());
+        assert_eq!("0".parse::<Polynomials<Fq>>().unwrap(), Polynomials::zero());
+        // Coefficients are reduced modulo p
+        assert_eq!("18x + 20".parse::<Polynomials<F17>>().unwrap().to_string(), "x + 3");
+
+        let sparse: SparsePolynomial<Fq> = "x^1000000 - 1".parse().unwrap();
+        assert_eq!(sparse.terms(), &vec![(0, Fq::from(-1)), (1000000, Fq::from(1))]);
+    }
+
+    #[test]
+    fn should_round_trip_through_display() {
+        let original = poly(&[12, 0, -3, 1, 0, 0, -44, 7]);
+        assert_eq!(original.to_string().parse::<Polynomials<Fq>>().unwrap(), original);
+    }
+
+    #[test]
+    fn should_reject_malformed_input() {
+        for input in ["", "   ", "3x^", "3y^2", "x^2 +", "2**x", "x^-1", "3x^2x", "+-x", "1.5x"] {
+            assert!(
+                matches!(input.parse::<Polynomials<Fq>>(), Err(PolynomialError::ParseError(_))),
+                "{} should not parse",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_oversized_exponents() {
+        for input in ["x^18446744073709551615", "x^18446744073709551616", "x^4294967296 + 1", "3x^1048577"] {
+            assert!(
+                matches!(input.parse::<Polynomials<Fq>>(), Err(PolynomialError::ParseError(_))),
+                "{} should not parse",
+                input
+            );
+        }
+        assert!("x^18446744073709551615".parse::<SparsePolynomial<Fq>>().is_err());
+
+        // Huge degrees still parse in sparse form
+        let sparse: SparsePolynomial<Fq> = "x^4294967296 + 1".parse().unwrap();
+        assert_eq!(sparse.degree(), Some(4294967296));
+        assert_eq!("x^1048576".parse::<Polynomials<Fq>>().unwrap().degree(), Some(1048576));
+    }
+}
@@ -0,0 +1,215 @@
+use ark_ff::PrimeField;
+
+use super::{arithmetics::mul_coefficients, fft::root_of_unity, PolynomialError, Polynomials};
+
+// Quotients at least this long are computed with Newton iteration
+// when the field supports the NTT for the intermediate products.
+const FAST_DIVISION_THRESHOLD: usize = 64;
+
+impl<F: PrimeField> Polynomials<F> {
+    // Long division, returns (quotient, remainder) such that
+    // self = quotient * divisor + remainder and deg(remainder) < deg(divisor)
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(dividend = self.coefficients().len(), divisor = divisor.coefficients().len())))]
+    pub fn div_rem(&self, divisor: &Polynomials<F>) -> Result<(Polynomials<F>, Polynomials<F>), PolynomialError> {
+        if divisor.is_zero() {
+            return Err(PolynomialError::DivisionByZero);
+        }
+        let divisor = divisor.coefficients();
+
+        // Linear divisors c1 * x + c0 take the synthetic division path
+        if divisor.len() == 2 {
+            let leading_inv = divisor[1].inverse().ok_or(PolynomialError::DivisionByZero)?;
+            let (quotient, remainder) = self.div_by_linear(-divisor[0] * leading_inv);
+            return Ok((quotient.scalar_mul(leading_inv), Polynomials::from_coefficients(vec![remainder])));
+        }
+
+        if self.coefficients().len() < divisor.len() {
+            return Ok((Polynomials::zero(), self.clone()));
+        }
+
+        let quotient_len = self.coefficients().len() - divisor.len() + 1;
+        if quotient_len >= FAST_DIVISION_THRESHOLD
+            && divisor.len() >= FAST_DIVISION_THRESHOLD
+            && root_of_unity::<F>((2 * self.coefficients().len()).next_power_of_two()).is_some()
+        {
+            return self.fast_div_rem(divisor);
+        }
+
+        let mut remainder = self.coefficients().clone();
+
+        let leading_inv = divisor[divisor.len() - 1].inverse().ok_or(PolynomialError::DivisionByZero)?;
+        let mut quotient = vec![F::zero(); remainder.len() - divisor.len() + 1];
+
+        // Eliminate the highest remaining term at every step
+        for i in (0..quotient.len()).rev() {
+            let coefficient = remainder[i + divisor.len() - 1] * leading_inv;
+            quotient[i] = coefficient;
+            for (j, divisor_coefficient) in divisor.iter().enumerate() {
+                remainder[i + j] -= coefficient * divisor_coefficient;
+            }
+        }
+
+        // Constant divisors leave the zero polynomial
+        remainder.truncate((divisor.len() - 1).max(1));
+
+        Ok((Polynomials::from_coefficients(quotient), Polynomials::from_coefficients(remainder)))
+    }
+
+    // Division through the reversed polynomials in O(n log n):
+    // rev(q) = rev(a) * rev(b)^-1 mod x^(deg a - deg b + 1), then r = a - q * b
+    fn fast_div_rem(&self, divisor: &[F]) -> Result<(Polynomials<F>, Polynomials<F>), PolynomialError> {
+        let dividend = self.coefficients();
+        let quotient_len = dividend.len() - divisor.len() + 1;
+
+        let reversed_divisor: Vec<F> = divisor.iter().rev().copied().collect();
+        let reversed_dividend: Vec<F> = dividend.iter().rev().take(quotient_len).copied().collect();
+
+        let inverse = power_series_inverse(&reversed_divisor, quotient_len)?;
+        let mut reversed_quotient = mul_coefficients(&reversed_dividend, &inverse);
+        reversed_quotient.resize(quotient_len, F::zero());
+        reversed_quotient.reverse();
+
+        let quotient = Polynomials::from_coefficients(reversed_quotient);
+        let remainder = self - &Polynomials::from_coefficients(mul_coefficients(quotient.coefficients(), divisor));
+
+        Ok((quotient, remainder))
+    }
+
+    pub fn try_div(&self, divisor: &Polynomials<F>) -> Result<Polynomials<F>, PolynomialError> {
+        self.div_rem(divisor).map(|(quotient, _)| quotient)
+    }
+
+    pub fn try_rem(&self, divisor: &Polynomials<F>) -> Result<Polynomials<F>, PolynomialError> {
+        self.div_rem(divisor).map(|(_, remainder)| remainder)
+    }
+
+    // Synthetic division by (x - z), returns the quotient and the remainder f(z).
+    // The quotient is (f(x) - f(z)) / (x - z), as used by opening proofs.
+    pub fn div_by_linear(&self, z: F) -> (Polynomials<F>, F) {
+        let coefficients = self.coefficients();
+        if coefficients.len() == 1 {
+            return (Polynomials::zero(), coefficients[0]);
+        }
+
+        // From the highest coefficient down: b[k - 1] = a[k] + z * b[k]
+        let mut quotient = vec![F::zero(); coefficients.len() - 1];
+        let mut carry = F::zero();
+        for i in (1..coefficients.len()).rev() {
+            carry = coefficients[i] + z * carry;
+            quotient[i - 1] = carry;
+        }
+        let remainder = coefficients[0] + z * carry;
+
+        (Polynomials::from_coefficients(quotient), remainder)
+    }
+}
+
+// Inverse of the power series f modulo x^precision by Newton iteration,
+// each step doubles the precision: g = g * (2 - f * g) mod x^2l
+fn power_series_inverse<F: PrimeField>(f: &[F], precision: usize) -> Result<Vec<F>, PolynomialError> {
+    let mut inverse = vec![f[0].inverse().ok_or(PolynomialError::DivisionByZero)?];
+    let mut length = 1;
+
+    while length < precision {
+        length = (2 * length).min(precision);
+
+        let truncated = &f[..length.min(f.len())];
+        let mut error = mul_coefficients(truncated, &inverse);
+        error.resize(length, F::zero());
+        for value in error.iter_mut() {
+            *value = -*value;
+        }
+        error[0] += F::from(2u64);
+
+        inverse = mul_coefficients(&inverse, &error);
+        inverse.truncate(length);
+    }
+
+    Ok(inverse)
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::{Fq, Fr};
+    use ark_ff::{AdditiveGroup, Field};
+
+    use super::*;
+    use crate::univariate::types::PolynomialTrait;
+
+    #[test]
+    fn should_divide_polynomials() {
+        // 3x^5 + 5x^3 - 7x - 5
+        let dividend = Polynomials::new(
+            vec![Fq::from(-5), Fq::from(-7), Fq::ZERO, Fq::from(5), Fq::ZERO, Fq::from(3)]
+        ).unwrap();
+        // x^2 + 1
+        let divisor = Polynomials::new(
+            vec![Fq::ONE, Fq::ZERO, Fq::ONE]
+        ).unwrap();
+
+        // 3x^5 + 5x^3 - 7x - 5 = (3x^3 + 2x)(x^2 + 1) - 9x - 5
+        let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+        assert_eq!(quotient.coefficients(), &vec![Fq::ZERO, Fq::from(2), Fq::ZERO, Fq::from(3)]);
+        assert_eq!(remainder.coefficients(), &vec![Fq::from(-5), Fq::from(-9)]);
+
+        // The quotient of a smaller dividend is zero
+        let (quotient, remainder) = divisor.div_rem(&dividend).unwrap();
+        assert_eq!(quotient.coefficients(), &vec![Fq::ZERO]);
+        assert_eq!(remainder.coefficients(), divisor.coefficients());
+
+        // Dividing by a constant only scales
+        let (quotient, remainder) = divisor.div_rem(&Polynomials::new(vec![Fq::from(2)]).unwrap()).unwrap();
+        assert_eq!(quotient, divisor.scalar_mul(Fq::ONE / Fq::from(2)));
+        assert!(remainder.is_zero());
+    }
+
+    #[test]
+    fn should_divide_by_linear_polynomials() {
+        // x^3 + 9x^2 - 9
+        let dividend = Polynomials::new(
+            vec![Fq::from(-9), Fq::ZERO, Fq::from(9), Fq::ONE]
+        ).unwrap();
+
+        // x^3 + 9x^2 - 9 = (x^2 + 11x + 22)(x - 2) + 35
+        let (quotient, remainder) = dividend.div_by_linear(Fq::from(2));
+        assert_eq!(quotient.coefficients(), &vec![Fq::from(22), Fq::from(11), Fq::ONE]);
+        assert_eq!(remainder, dividend.evaluate(Fq::from(2)));
+
+        // Same through div_rem with 2x - 4, the quotient is halved
+        let divisor = Polynomials::new(vec![Fq::from(-4), Fq::from(2)]).unwrap();
+        let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+        assert_eq!(quotient.coefficients(), &vec![Fq::from(11), Fq::from(11) / Fq::from(2), Fq::ONE / Fq::from(2)]);
+        assert_eq!(remainder.coefficients(), &vec![Fq::from(35)]);
+    }
+
+    #[test]
+    fn should_divide_large_polynomials_with_newton_iteration() {
+        // bn254 Fr is FFT friendly, these sizes take the fast path
+        let dividend = Polynomials::new(
+            (0..400u64).map(|i| Fr::from(i * i + 3 * i + 1)).collect()
+        ).unwrap();
+        let divisor = Polynomials::new(
+            (0..150u64).map(|i| Fr::from(7 * i + 2)).collect()
+        ).unwrap();
+
+        let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+        assert_eq!(quotient.degree(), Some(250));
+        assert!(remainder.degree().unwrap() < 149);
+        assert_eq!(&(&quotient * &divisor) + &remainder, dividend);
+
+        // An exact multiple leaves no remainder
+        let (quotient, remainder) = (&dividend * &divisor).div_rem(&divisor).unwrap();
+        assert_eq!(quotient, dividend);
+        assert!(remainder.is_zero());
+    }
+
+    #[test]
+    fn should_not_divide_by_zero() {
+        let dividend = Polynomials::new(vec![Fq::from(5), Fq::from(3)]).unwrap();
+        let divisor = Polynomials::new(vec![Fq::ZERO, Fq::ZERO]).unwrap();
+
+        assert!(matches!(dividend.div_rem(&divisor), Err(PolynomialError::DivisionByZero)));
+        assert_eq!(dividend.try_div(&divisor), Err(PolynomialError::DivisionByZero));
+        assert_eq!(dividend.try_rem(&divisor), Err(PolynomialError::DivisionByZero));
+    }
+}
@@ -0,0 +1,412 @@
+// An evaluation domain is a multiplicative subgroup H = {1, w, w^2, ..., w^(n-1)}
+// of size n = 2^k, or one of its cosets gH = {g, gw, ..., gw^(n-1)}.
+// A polynomial of degree < n is uniquely determined by its n values over the domain,
+// and the NTT converts between the two forms in O(n log n).
+// A low-degree extension re-evaluates the same polynomial over a larger coset.
+
+use std::ops::{Add, Div, Mul, Sub};
+
+use ark_ff::PrimeField;
+
+use super::{
+    fft::{coset_intt, ntt, root_of_unity},
+    lagrange::batch_inverse,
+    PolynomialError, Polynomials,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Domain<F: PrimeField> {
+    // Number of elements, always a power of two
+    size: usize,
+    // Primitive root of unity of order `size`
+    generator: F,
+    // Every element is multiplied by the offset, 1 for the subgroup itself
+    offset: F,
+}
+
+impl<F: PrimeField> Domain<F> {
+    // Smallest subgroup holding at least `size` elements
+    pub fn new(size: usize) -> Result<Self, PolynomialError> {
+        Self::new_coset(size, F::one())
+    }
+
+    pub fn new_coset(size: usize, offset: F) -> Result<Self, PolynomialError> {
+        if offset.is_zero() {
+            return Err(PolynomialError::DomainError);
+        }
+        let size = size.max(1).next_power_of_two();
+        let generator = root_of_unity::<F>(size).ok_or(PolynomialError::DomainError)?;
+
+        Ok(Domain {
+            size,
+            generator,
+            offset,
+        })
+    }
+
+    // Same subgroup shifted by `offset`
+    pub fn coset(&self, offset: F) -> Result<Self, PolynomialError> {
+        Self::new_coset(self.size, offset)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn generator(&self) -> F {
+        self.generator
+    }
+
+    pub fn offset(&self) -> F {
+        self.offset
+    }
+
+    pub fn is_subgroup(&self) -> bool {
+        self.offset.is_one()
+    }
+
+    // The i-th element g * w^i
+    pub fn element(&self, index: usize) -> F {
+        self.offset * self.generator.pow([index as u64])
+    }
+
+    pub fn elements(&self) -> Vec<F> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut current = self.offset;
+        for _ in 0..self.size {
+            elements.push(current);
+            current *= self.generator;
+        }
+        elements
+    }
+
+    // Evaluates the coefficients over the domain.
+    // Coefficients beyond the domain size wrap around since w^n = 1.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(size = self.size, coefficients = coefficients.len())))]
+    pub fn fft(&self, coefficients: &[F]) -> Vec<F> {
+        let mut values = vec![F::zero(); self.size];
+        let mut power = F::one();
+        for (i, coefficient) in coefficients.iter().enumerate() {
+            values[i % self.size] += *coefficient * power;
+            power *= self.offset;
+        }
+
+        ntt(&mut values, self.generator);
+        values
+    }
+
+    // Interpolates the coefficients of the unique polynomial of degree < n
+    // taking the given values over the domain.
+    pub fn ifft(&self, evaluations: &[F]) -> Vec<F> {
+        self.try_ifft(evaluations).expect("Evaluations must cover the domain")
+    }
+
+    // Fails unless there is exactly one value per element of the domain
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(size = self.size)))]
+    pub fn try_ifft(&self, evaluations: &[F]) -> Result<Vec<F>, PolynomialError> {
+        if evaluations.len() != self.size {
+            return Err(PolynomialError::LengthMismatch {
+                expected: self.size,
+                found: evaluations.len(),
+            });
+        }
+
+        let mut coefficients = evaluations.to_vec();
+        coset_intt(&mut coefficients, self.generator, self.offset);
+        Ok(coefficients)
+    }
+}
+
+// A polynomial given by its values over a domain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evaluations<F: PrimeField> {
+    evaluations: Vec<F>,
+    domain: Domain<F>,
+}
+
+impl<F: PrimeField> Evaluations<F> {
+    pub fn new(evaluations: Vec<F>, domain: Domain<F>) -> Result<Self, PolynomialError> {
+        if evaluations.len() != domain.size() {
+            return Err(PolynomialError::LengthMismatch {
+                expected: domain.size(),
+                found: evaluations.len(),
+            });
+        }
+
+        Ok(Evaluations {
+            evaluations,
+            domain,
+        })
+    }
+
+    pub fn evaluations(&self) -> &Vec<F> {
+        &self.evaluations
+    }
+
+    pub fn domain(&self) -> &Domain<F> {
+        &self.domain
+    }
+
+    // Converts back to coefficient form
+    pub fn interpolate(&self) -> Polynomials<F> {
+        self.try_interpolate().expect("Evaluations must cover the domain")
+    }
+
+    pub fn try_interpolate(&self) -> Result<Polynomials<F>, PolynomialError> {
+        Ok(Polynomials::from_coefficients(self.domain.try_ifft(&self.evaluations)?))
+    }
+
+    // Evaluations of the same polynomial over the coset offset * H' with |H'| = blowup * n,
+    // one IFFT and one FFT instead of evaluating point by point.
+    // The blowup must be a power of two.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(size = self.domain.size, blowup)))]
+    pub fn low_degree_extend(&self, blowup: usize, offset: F) -> Result<Evaluations<F>, PolynomialError> {
+        if !blowup.is_power_of_two() {
+            return Err(PolynomialError::NotPowerOfTwo(blowup));
+        }
+        let domain = Domain::new_coset(self.domain.size * blowup, offset)?;
+
+        Ok(Evaluations {
+            evaluations: domain.fft(&self.domain.try_ifft(&self.evaluations)?),
+            domain,
+        })
+    }
+}
+
+// Extends evaluations over the subgroup of size n = evaluations.len() to the coset
+// g * H' of size n * blowup, g being the multiplicative generator of F so the two never meet.
+pub fn low_degree_extend<F: PrimeField>(evaluations: &[F], blowup: usize) -> Result<Vec<F>, PolynomialError> {
+    if !evaluations.len().is_power_of_two() {
+        return Err(PolynomialError::NotPowerOfTwo(evaluations.len()));
+    }
+    let evaluations = Evaluations::new(evaluations.to_vec(), Domain::new(evaluations.len())?)?;
+
+    Ok(evaluations.low_degree_extend(blowup, F::GENERATOR)?.evaluations)
+}
+
+impl<F: PrimeField> Polynomials<F> {
+    // Converts to evaluation form over the domain
+    pub fn evaluate_over_domain(&self, domain: &Domain<F>) -> Evaluations<F> {
+        Evaluations {
+            evaluations: domain.fft(self.coefficients()),
+            domain: *domain,
+        }
+    }
+}
+
+impl<F: PrimeField> Evaluations<F> {
+    pub fn try_add(&self, other: &Evaluations<F>) -> Result<Evaluations<F>, PolynomialError> {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    pub fn try_sub(&self, other: &Evaluations<F>) -> Result<Evaluations<F>, PolynomialError> {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    pub fn try_mul(&self, other: &Evaluations<F>) -> Result<Evaluations<F>, PolynomialError> {
+        self.zip_with(other, |a, b| a * b)
+    }
+
+    // Fails when the divisor vanishes somewhere on the domain
+    pub fn try_div(&self, other: &Evaluations<F>) -> Result<Evaluations<F>, PolynomialError> {
+        // One inversion for the whole domain
+        let mut inverses = other.clone();
+        batch_inverse(&mut inverses.evaluations)?;
+
+        self.zip_with(&inverses, |a, b| a * b)
+    }
+
+    // Pointwise operation, both sides must share the domain
+    fn zip_with(&self, other: &Evaluations<F>, op: impl Fn(F, F) -> F) -> Result<Evaluations<F>, PolynomialError> {
+        if self.domain != other.domain {
+            return Err(PolynomialError::DomainMismatch);
+        }
+
+        let evaluations = self
+            .evaluations
+            .iter()
+            .zip(other.evaluations.iter())
+            .map(|(a, b)| op(*a, *b))
+            .collect();
+        Ok(Evaluations {
+            evaluations,
+            domain: self.domain,
+        })
+    }
+}
+
+impl<F: PrimeField> Add for &Evaluations<F> {
+    type Output = Evaluations<F>;
+
+    fn add(self, other: &Evaluations<F>) -> Evaluations<F> {
+        self.try_add(other).expect("The evaluations must share a domain")
+    }
+}
+
+impl<F: PrimeField> Sub for &Evaluations<F> {
+    type Output = Evaluations<F>;
+
+    fn sub(self, other: &Evaluations<F>) -> Evaluations<F> {
+        self.try_sub(other).expect("The evaluations must share a domain")
+    }
+}
+
+impl<F: PrimeField> Mul for &Evaluations<F> {
+    type Output = Evaluations<F>;
+
+    fn mul(self, other: &Evaluations<F>) -> Evaluations<F> {
+        self.try_mul(other).expect("The evaluations must share a domain")
+    }
+}
+
+impl<F: PrimeField> Div for &Evaluations<F> {
+    type Output = Evaluations<F>;
+
+    fn div(self, other: &Evaluations<F>) -> Evaluations<F> {
+        self.try_div(other).expect("The divisor must not vanish on the domain")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::{Fq, Fr};
+    use ark_ff::{FftField, Field, One};
+
+    use super::*;
+    use crate::univariate::types::PolynomialTrait;
+
+    #[test]
+    fn should_create_domain() {
+        let domain = Domain::<Fr>::new(5).unwrap();
+        assert_eq!(domain.size(), 8);
+        assert_eq!(domain.generator().pow([8u64]), Fr::one());
+        assert_eq!(domain.elements().len(), 8);
+        assert_eq!(domain.element(3), domain.elements()[3]);
+
+        let coset = domain.coset(Fr::from(7)).unwrap();
+        assert!(!coset.is_subgroup());
+        assert_eq!(coset.element(0), Fr::from(7));
+        assert_eq!(coset.element(2), Fr::from(7) * domain.element(2));
+
+        // bn254 Fq has no subgroup of size 8
+        assert!(Domain::<Fq>::new(8).is_err());
+        assert!(Domain::<Fr>::new_coset(8, Fr::from(0)).is_err());
+    }
+
+    #[test]
+    fn should_convert_between_forms() {
+        // 3x^3 + 5x^2 - 7x - 5
+        let poly = Polynomials::new(
+            vec![Fr::from(-5), Fr::from(-7), Fr::from(5), Fr::from(3)]
+        ).unwrap();
+
+        for domain in [Domain::new(4).unwrap(), Domain::new_coset(8, Fr::from(5)).unwrap()] {
+            let evaluations = poly.evaluate_over_domain(&domain);
+            for (x, value) in domain.elements().iter().zip(evaluations.evaluations()) {
+                assert_eq!(poly.evaluate(*x), *value);
+            }
+
+            assert_eq!(evaluations.interpolate(), poly);
+        }
+    }
+
+    #[test]
+    fn should_reject_evaluations_not_covering_the_domain() {
+        let domain = Domain::<Fr>::new(4).unwrap();
+        let values = vec![Fr::from(1), Fr::from(2), Fr::from(3)];
+        assert_eq!(
+            Evaluations::new(values.clone(), domain),
+            Err(PolynomialError::LengthMismatch { expected: 4, found: 3 })
+        );
+        assert_eq!(domain.try_ifft(&values), Err(PolynomialError::LengthMismatch { expected: 4, found: 3 }));
+    }
+
+    #[test]
+    fn should_wrap_high_degree_coefficients() {
+        // x^5 + 2 over a domain of size 4
+        let poly = Polynomials::new(
+            vec![Fr::from(2), Fr::from(0), Fr::from(0), Fr::from(0), Fr::from(0), Fr::from(1)]
+        ).unwrap();
+        let domain = Domain::new_coset(4, Fr::from(3)).unwrap();
+
+        let evaluations = poly.evaluate_over_domain(&domain);
+        for (x, value) in domain.elements().iter().zip(evaluations.evaluations()) {
+            assert_eq!(poly.evaluate(*x), *value);
+        }
+    }
+
+    #[test]
+    fn should_extend_to_a_larger_coset() {
+        // 3x^3 + 5x^2 - 7x - 5
+        let poly = Polynomials::new(
+            vec![Fr::from(-5), Fr::from(-7), Fr::from(5), Fr::from(3)]
+        ).unwrap();
+        let domain = Domain::new(4).unwrap();
+        let values = poly.evaluate_over_domain(&domain);
+
+        let extended = values.low_degree_extend(4, Fr::from(7)).unwrap();
+        assert_eq!(extended.domain(), &Domain::new_coset(16, Fr::from(7)).unwrap());
+        assert_eq!(extended.evaluations(), &poly.evaluate_batch(&extended.domain().elements()));
+        assert_eq!(extended.interpolate(), poly);
+
+        let extended = low_degree_extend(values.evaluations(), 2).unwrap();
+        let coset = Domain::new_coset(8, Fr::GENERATOR).unwrap();
+        assert_eq!(extended, poly.evaluate_batch(&coset.elements()));
+
+        assert_eq!(low_degree_extend(values.evaluations(), 3), Err(PolynomialError::NotPowerOfTwo(3)));
+        assert_eq!(low_degree_extend(&values.evaluations()[..3], 2), Err(PolynomialError::NotPowerOfTwo(3)));
+        assert_eq!(values.low_degree_extend(2, Fr::from(0)), Err(PolynomialError::DomainError));
+    }
+
+    #[test]
+    fn should_do_pointwise_arithmetic() {
+        let domain = Domain::<Fr>::new(4).unwrap();
+        // x + 1
+        let poly_a = Polynomials::new(vec![Fr::from(1), Fr::from(1)]).unwrap();
+        // 2x - 3
+        let poly_b = Polynomials::new(vec![Fr::from(-3), Fr::from(2)]).unwrap();
+
+        let evals_a = poly_a.evaluate_over_domain(&domain);
+        let evals_b = poly_b.evaluate_over_domain(&domain);
+
+        // 3x - 2
+        let sum = (&evals_a + &evals_b).interpolate();
+        assert_eq!(sum.coefficients(), &vec![Fr::from(-2), Fr::from(3)]);
+
+        // -x + 4
+        let difference = (&evals_a - &evals_b).interpolate();
+        assert_eq!(difference.coefficients(), &vec![Fr::from(4), Fr::from(-1)]);
+
+        // 2x^2 - x - 3
+        let product = (&evals_a * &evals_b).interpolate();
+        assert_eq!(product, &poly_a * &poly_b);
+
+        let quotient = &(&evals_a * &evals_b) / &evals_b;
+        assert_eq!(quotient, evals_a);
+    }
+
+    #[test]
+    fn should_reject_mismatched_evaluations() {
+        let domain = Domain::<Fr>::new(4).unwrap();
+        let coset = domain.coset(Fr::from(3)).unwrap();
+        // x - 1 vanishes at the first element of the subgroup
+        let poly = Polynomials::new(vec![Fr::from(-1), Fr::from(1)]).unwrap();
+
+        let evals = poly.evaluate_over_domain(&domain);
+        let coset_evals = poly.evaluate_over_domain(&coset);
+        assert_eq!(evals.try_add(&coset_evals), Err(PolynomialError::DomainMismatch));
+        assert_eq!(evals.try_div(&evals), Err(PolynomialError::DivisionByZero));
+        assert!(coset_evals.try_div(&coset_evals).is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_not_divide_by_vanishing_evaluations() {
+        let domain = Domain::<Fr>::new(4).unwrap();
+        // x - 1 vanishes at the first element
+        let poly = Polynomials::new(vec![Fr::from(-1), Fr::from(1)]).unwrap();
+        let evals = poly.evaluate_over_domain(&domain);
+
+        let _ = &evals / &evals;
+    }
+}
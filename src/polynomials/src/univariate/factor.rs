@@ -0,0 +1,285 @@
+// Factorization over the prime field F_p in three stages:
+// - square-free: f = prod_i f_i^i with every f_i square-free, using gcd(f, f')
+//   and taking p-th roots when f' vanishes on a factor,
+// - distinct-degree: splits a square-free f into products of irreducibles of equal degree d,
+//   since x^(p^d) - x is the product of all monic irreducibles of degree dividing d,
+// - equal-degree (Cantor–Zassenhaus): splits such a product with random polynomials.
+
+use ark_ff::PrimeField;
+use num_bigint::BigUint;
+use rand::Rng;
+
+use super::Polynomials;
+
+impl<F: PrimeField> Polynomials<F> {
+    // The monic irreducible factors with their exponents, sorted by degree then coefficients.
+    // The leading coefficient is dropped, zero and constants have no factors.
+    pub fn factor(&self) -> Vec<(Polynomials<F>, usize)> {
+        self.factor_with_rng(&mut rand::rng())
+    }
+
+    // Same as factor, with the randomness of the equal-degree step taken from `rng`
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(degree = ?self.degree())))]
+    pub fn factor_with_rng<R: Rng>(&self, rng: &mut R) -> Vec<(Polynomials<F>, usize)> {
+        let mut factors = vec![];
+        for (square_free, multiplicity) in self.monic().square_free_factors() {
+            for (product, degree) in square_free.distinct_degree_factors() {
+                let mut irreducibles = vec![];
+                equal_degree_split(&product, degree, rng, &mut irreducibles);
+                factors.extend(irreducibles.into_iter().map(|factor| (factor, multiplicity)));
+            }
+        }
+
+        factors.sort_by(|(a, _), (b, _)| a.degree().cmp(&b.degree()).then_with(|| a.coefficients().cmp(b.coefficients())));
+        factors
+    }
+
+    // Rabin's test: f of degree n is irreducible iff x^(p^n) = x mod f
+    // and gcd(f, x^(p^(n/q)) - x) = 1 for every prime q dividing n
+    pub fn is_irreducible(&self) -> bool {
+        let n = match self.degree() {
+            None | Some(0) => return false,
+            Some(1) => return true,
+            Some(n) => n,
+        };
+        let f = self.monic();
+        let x = Polynomials::from_coefficients(vec![F::zero(), F::one()]);
+
+        for q in prime_divisors(n) {
+            let h = f.frobenius(&x, n / q);
+            if f.gcd(&(&h - &x)).degree() != Some(0) {
+                return false;
+            }
+        }
+
+        f.frobenius(&x, n) == x
+    }
+
+    // f = prod_i (f_i, i) for a monic f, skipping the factors equal to 1
+    fn square_free_factors(&self) -> Vec<(Polynomials<F>, usize)> {
+        if self.degree().is_none_or(|degree| degree == 0) {
+            return vec![];
+        }
+
+        let mut factors = vec![];
+        let mut c = self.gcd(&self.derivative());
+        let mut w = self.try_div(&c).expect("The gcd is never zero");
+
+        // w holds the product of the factors with multiplicity >= i that are not p-th powers
+        let mut i = 1;
+        while w.degree() != Some(0) {
+            let y = w.gcd(&c);
+            let factor = w.try_div(&y).expect("The gcd is never zero");
+            if factor.degree() != Some(0) {
+                factors.push((factor, i));
+            }
+            c = c.try_div(&y).expect("The gcd is never zero");
+            w = y;
+            i += 1;
+        }
+
+        // What remains is a p-th power, only possible once the degree reaches p
+        if c.degree() != Some(0) {
+            let p = small_characteristic::<F>().expect("Only a degree of at least p has a vanishing derivative");
+            let root = c.pth_root(p);
+            factors.extend(root.square_free_factors().into_iter().map(|(factor, multiplicity)| (factor, multiplicity * p)));
+        }
+
+        factors
+    }
+
+    // For a monic square-free f, the products of its irreducible factors of each degree d
+    fn distinct_degree_factors(&self) -> Vec<(Polynomials<F>, usize)> {
+        let mut factors = vec![];
+        let mut f = self.clone();
+        let x = Polynomials::from_coefficients(vec![F::zero(), F::one()]);
+
+        // h = x^(p^d) mod f
+        let mut h = x.clone();
+        let mut d = 1;
+        while f.degree().is_some_and(|degree| degree >= 2 * d) {
+            h = f.frobenius(&h, 1);
+            let g = f.gcd(&(&h - &x));
+            if g.degree() != Some(0) {
+                f = f.try_div(&g).expect("The gcd is never zero");
+                h = h.try_rem(&f).expect("The polynomial is never zero");
+                factors.push((g, d));
+            }
+            d += 1;
+        }
+
+        // A remaining factor of degree < 2d is irreducible
+        if let Some(degree) = f.degree().filter(|degree| *degree > 0) {
+            factors.push((f, degree));
+        }
+
+        factors
+    }
+
+    // poly^(p^k) mod self
+    fn frobenius(&self, poly: &Polynomials<F>, k: usize) -> Polynomials<F> {
+        (0..k).fold(poly.clone(), |acc, _| acc.pow_mod(F::MODULUS.as_ref(), self).expect("The polynomial is never zero"))
+    }
+
+    // g with g^p = self, when every exponent of self is a multiple of p.
+    // In F_p every coefficient is its own p-th root.
+    fn pth_root(&self, p: usize) -> Polynomials<F> {
+        Polynomials::from_coefficients(self.coefficients().iter().step_by(p).copied().collect())
+    }
+}
+
+// Splits a monic, square-free product of irreducibles of degree d into those irreducibles
+pub(crate) fn equal_degree_split<F: PrimeField, R: Rng>(
+    g: &Polynomials<F>,
+    d: usize,
+    rng: &mut R,
+    factors: &mut Vec<Polynomials<F>>,
+) {
+    let n = match g.degree() {
+        None | Some(0) => return,
+        Some(n) if n == d => {
+            factors.push(g.clone());
+            return;
+        }
+        Some(n) => n,
+    };
+
+    let one = Polynomials::from_coefficients(vec![F::one()]);
+    let characteristic: BigUint = F::MODULUS.into();
+    let field_size = characteristic.pow(d as u32);
+    loop {
+        let a = Polynomials::from_coefficients((0..n).map(|_| F::from(rng.random::<u64>())).collect());
+
+        let b = if characteristic == BigUint::from(2u32) {
+            // The trace a + a^2 + ... + a^(2^(d - 1)) takes the values 0 and 1
+            // on each factor with about equal probability
+            let mut power = a.clone();
+            let mut trace = a.clone();
+            for _ in 1..d {
+                power = g.frobenius(&power, 1);
+                trace += &power;
+            }
+            trace
+        } else {
+            // a^((p^d - 1) / 2) is 1 on about half the factors, -1 or 0 on the rest
+            let exponent: BigUint = (&field_size - 1u32) / 2u32;
+            &a.pow_mod(&exponent.to_u64_digits(), g).expect("The polynomial is never zero") - &one
+        };
+
+        let factor = g.gcd(&b);
+        if factor.degree().is_some_and(|degree| degree > 0 && degree < n) {
+            let cofactor = g.try_div(&factor).expect("The factor is never zero");
+            equal_degree_split(&factor, d, rng, factors);
+            equal_degree_split(&cofactor, d, rng, factors);
+            return;
+        }
+    }
+}
+
+// p as a usize when it fits
+fn small_characteristic<F: PrimeField>() -> Option<usize> {
+    let limbs = F::MODULUS;
+    let limbs = limbs.as_ref();
+    if limbs[1..].iter().any(|limb| *limb != 0) {
+        return None;
+    }
+    usize::try_from(limbs[0]).ok()
+}
+
+fn prime_divisors(mut n: usize) -> Vec<usize> {
+    let mut primes = vec![];
+    let mut q = 2;
+    while q * q <= n {
+        if n.is_multiple_of(q) {
+            primes.push(q);
+            while n.is_multiple_of(q) {
+                n /= q;
+            }
+        }
+        q += 1;
+    }
+    if n > 1 {
+        primes.push(n);
+    }
+    primes
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fq;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::univariate::mocks::{poly, F17};
+
+    fn expand<F: PrimeField>(factors: &[(Polynomials<F>, usize)]) -> Polynomials<F> {
+        factors
+            .iter()
+            .flat_map(|(factor, exponent)| std::iter::repeat_n(factor, *exponent))
+            .product()
+    }
+
+    #[test]
+    fn should_factor_polynomials() {
+        let mut rng = StdRng::seed_from_u64(5);
+
+        // 2 (x - 1)^2 (x + 3) (x^2 + 1)^3
+        let square = poly::<Fq>(&[1, 0, 1]);
+        let f = &(&(&poly(&[-1, 1]) * &poly(&[-1, 1])) * &poly(&[6, 2])) * &(&square * &(&square * &square));
+        let factors = f.factor_with_rng(&mut rng);
+        // x + 3 sorts before x - 1 since -1 is p - 1
+        assert_eq!(factors, vec![(poly(&[3, 1]), 1), (poly(&[-1, 1]), 2), (poly(&[1, 0, 1]), 3)]);
+        assert_eq!(expand(&factors), f.monic());
+
+        assert_eq!(poly::<Fq>(&[7]).factor_with_rng(&mut rng), vec![]);
+        assert_eq!(Polynomials::<Fq>::zero().factor_with_rng(&mut rng), vec![]);
+    }
+
+    #[test]
+    fn should_factor_over_a_small_field() {
+        let mut rng = StdRng::seed_from_u64(9);
+
+        // x^16 - 1 splits into the 16 linear factors x - a, a != 0
+        let mut coefficients = vec![0; 17];
+        coefficients[0] = -1;
+        coefficients[16] = 1;
+        let factors = poly::<F17>(&coefficients).factor_with_rng(&mut rng);
+        assert_eq!(factors, (1..17).rev().map(|a| (poly::<F17>(&[-a, 1]), 1)).collect::<Vec<_>>());
+
+        // (x + 1)^17 = x^17 + 1 has a zero derivative
+        let mut coefficients = vec![0; 18];
+        coefficients[0] = 1;
+        coefficients[17] = 1;
+        assert_eq!(poly::<F17>(&coefficients).factor_with_rng(&mut rng), vec![(poly::<F17>(&[1, 1]), 17)]);
+
+        // (x^2 - 3)^2 (x^4 - 3) (x - 5) with x^2 - 3 and x^4 - 3 irreducible
+        let f = &(&poly::<F17>(&[-3, 0, 1]) * &poly::<F17>(&[-3, 0, 1])) * &(&poly::<F17>(&[-3, 0, 0, 0, 1]) * &poly::<F17>(&[-5, 1]));
+        let factors = f.factor_with_rng(&mut rng);
+        assert_eq!(factors, vec![(poly::<F17>(&[-5, 1]), 1), (poly::<F17>(&[-3, 0, 1]), 2), (poly::<F17>(&[-3, 0, 0, 0, 1]), 1)]);
+        assert_eq!(expand(&factors), f);
+    }
+
+    #[test]
+    fn should_test_irreducibility() {
+        // -1 is not a square in bn254 Fq
+        assert!(poly::<Fq>(&[1, 0, 1]).is_irreducible());
+        assert!(poly::<Fq>(&[3, 5]).is_irreducible());
+        assert!(!poly::<Fq>(&[-1, 0, 1]).is_irreducible());
+        assert!(!poly::<Fq>(&[4]).is_irreducible());
+
+        // 3 generates F17^*, so it is neither a square nor of the form -4b^4
+        assert!(poly::<F17>(&[-3, 0, 1]).is_irreducible());
+        assert!(poly::<F17>(&[-3, 0, 0, 0, 1]).is_irreducible());
+        // x^4 + 1 splits since 8 divides 17 - 1
+        assert!(!poly::<F17>(&[1, 0, 0, 0, 1]).is_irreducible());
+        // Product of two irreducible quadratics has no roots but is reducible
+        assert!(!(&poly::<F17>(&[-3, 0, 1]) * &poly::<F17>(&[-5, 0, 1])).is_irreducible());
+    }
+
+    #[test]
+    fn should_find_prime_divisors() {
+        assert_eq!(prime_divisors(12), vec![2, 3]);
+        assert_eq!(prime_divisors(13), vec![13]);
+        assert_eq!(prime_divisors(1), Vec::<usize>::new());
+    }
+}
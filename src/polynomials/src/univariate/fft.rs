@@ -0,0 +1,221 @@
+// Radix-2 number-theoretic transform (NTT) over the multiplicative subgroups of F.
+// A field is FFT friendly when it has a subgroup of size 2^k (k = F::TWO_ADICITY),
+// e.g. bn254 Fr has k = 28 while bn254 Fq only has k = 1.
+//
+// Multiplying two polynomials through the NTT is O(n log n):
+// evaluate both over a subgroup large enough for the product, multiply pointwise,
+// then interpolate back with the inverse transform.
+
+use ark_ff::{FftField, PrimeField};
+
+// Below this many coefficients (in the smaller operand) the schoolbook
+// multiplication is faster than three transforms.
+pub const NTT_MUL_THRESHOLD: usize = 32;
+
+// Returns the primitive root of unity of order `size`,
+// None when `size` is not a power of two or the field has no such subgroup.
+pub fn root_of_unity<F: PrimeField>(size: usize) -> Option<F> {
+    if !size.is_power_of_two() || size.trailing_zeros() > F::TWO_ADICITY {
+        return None;
+    }
+
+    let mut omega = F::TWO_ADIC_ROOT_OF_UNITY;
+    for _ in size.trailing_zeros()..F::TWO_ADICITY {
+        omega.square_in_place();
+    }
+    Some(omega)
+}
+
+// In-place forward transform. values.len() must be a power of two and
+// `omega` a primitive root of unity of that order.
+// values[i] becomes sum_j values[j] * omega^(i * j)
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(size = values.len())))]
+pub fn ntt<F: FftField>(values: &mut [F], omega: F) {
+    let n = values.len();
+    assert!(n.is_power_of_two(), "The NTT size must be a power of two");
+    if n == 1 {
+        return;
+    }
+
+    bit_reverse_permutation(values);
+
+    // Iterative Cooley-Tukey butterflies, doubling the chunk size every round
+    let mut half = 1;
+    while half < n {
+        let step = omega.pow([(n / (2 * half)) as u64]);
+        for chunk in values.chunks_mut(2 * half) {
+            let mut twiddle = F::one();
+            let (left, right) = chunk.split_at_mut(half);
+            for (a, b) in left.iter_mut().zip(right.iter_mut()) {
+                let t = *b * twiddle;
+                *b = *a - t;
+                *a += t;
+                twiddle *= step;
+            }
+        }
+        half *= 2;
+    }
+}
+
+// In-place inverse transform, undoes `ntt` with the same `omega`.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(size = values.len())))]
+pub fn intt<F: FftField>(values: &mut [F], omega: F) {
+    let omega_inv = omega.inverse().expect("A root of unity is never zero");
+    ntt(values, omega_inv);
+
+    let n_inv = F::from(values.len() as u64)
+        .inverse()
+        .expect("The NTT size must be invertible in the field");
+    for value in values.iter_mut() {
+        *value *= n_inv;
+    }
+}
+
+// In-place forward transform over the coset offset * <omega>,
+// values[i] becomes sum_j values[j] * (offset * omega^i)^j
+pub fn coset_ntt<F: FftField>(values: &mut [F], omega: F, offset: F) {
+    scale_by_powers(values, offset);
+    ntt(values, omega);
+}
+
+// In-place inverse transform, undoes `coset_ntt` with the same `omega` and `offset`.
+pub fn coset_intt<F: FftField>(values: &mut [F], omega: F, offset: F) {
+    intt(values, omega);
+    scale_by_powers(values, offset.inverse().expect("The coset offset must not be zero"));
+}
+
+// values[i] *= factor^i, turns f(x) into f(factor * x)
+fn scale_by_powers<F: FftField>(values: &mut [F], factor: F) {
+    if factor.is_one() {
+        return;
+    }
+
+    let mut power = F::one();
+    for value in values.iter_mut() {
+        *value *= power;
+        power *= factor;
+    }
+}
+
+// Multiplies two coefficient vectors through the NTT.
+// Returns None when the inputs are too small for the transform to pay off
+// or when the field has no subgroup large enough to hold the product.
+pub fn ntt_mul<F: PrimeField>(a: &[F], b: &[F]) -> Option<Vec<F>> {
+    if a.len().min(b.len()) < NTT_MUL_THRESHOLD {
+        return None;
+    }
+
+    let product_len = a.len() + b.len() - 1;
+    let size = product_len.next_power_of_two();
+    let omega = root_of_unity::<F>(size)?;
+
+    let mut a_evals = a.to_vec();
+    a_evals.resize(size, F::zero());
+    let mut b_evals = b.to_vec();
+    b_evals.resize(size, F::zero());
+
+    ntt(&mut a_evals, omega);
+    ntt(&mut b_evals, omega);
+    for (a, b) in a_evals.iter_mut().zip(b_evals.iter()) {
+        *a *= b;
+    }
+    intt(&mut a_evals, omega);
+
+    a_evals.truncate(product_len);
+    Some(a_evals)
+}
+
+fn bit_reverse_permutation<T>(values: &mut [T]) {
+    let n = values.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::{Fq, Fr};
+    use ark_ff::{Field, One, Zero};
+
+    use super::*;
+
+    fn sample(len: usize, seed: u64) -> Vec<Fr> {
+        (0..len as u64).map(|i| Fr::from(i * i + seed * i + 7)).collect()
+    }
+
+    #[test]
+    fn should_find_roots_of_unity() {
+        let omega = root_of_unity::<Fr>(8).unwrap();
+        assert_eq!(omega.pow([8u64]), Fr::one());
+        assert_ne!(omega.pow([4u64]), Fr::one());
+
+        // bn254 Fq only has a subgroup of size 2
+        assert!(root_of_unity::<Fq>(2).is_some());
+        assert!(root_of_unity::<Fq>(4).is_none());
+        assert!(root_of_unity::<Fr>(6).is_none());
+    }
+
+    #[test]
+    fn should_evaluate_over_the_subgroup() {
+        // 3x^2 + 2x + 1 over the subgroup of size 4
+        let coefficients = vec![Fr::from(1), Fr::from(2), Fr::from(3), Fr::zero()];
+        let omega = root_of_unity::<Fr>(4).unwrap();
+
+        let mut values = coefficients.clone();
+        ntt(&mut values, omega);
+        for (i, value) in values.iter().enumerate() {
+            let x = omega.pow([i as u64]);
+            assert_eq!(*value, Fr::from(1) + Fr::from(2) * x + Fr::from(3) * x * x);
+        }
+
+        intt(&mut values, omega);
+        assert_eq!(values, coefficients);
+    }
+
+    #[test]
+    fn should_evaluate_over_a_coset() {
+        let coefficients = sample(8, 3);
+        let omega = root_of_unity::<Fr>(8).unwrap();
+        let offset = Fr::from(5);
+
+        let mut values = coefficients.clone();
+        coset_ntt(&mut values, omega, offset);
+        for (i, value) in values.iter().enumerate() {
+            let x = offset * omega.pow([i as u64]);
+            let expected = coefficients.iter().rev().fold(Fr::zero(), |result, c| result * x + c);
+            assert_eq!(*value, expected);
+        }
+
+        coset_intt(&mut values, omega, offset);
+        assert_eq!(values, coefficients);
+    }
+
+    #[test]
+    fn should_multiply_with_ntt() {
+        let a = sample(100, 3);
+        let b = sample(150, 11);
+
+        let product = ntt_mul(&a, &b).unwrap();
+        let mut expected = vec![Fr::zero(); a.len() + b.len() - 1];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                expected[i + j] += *x * y;
+            }
+        }
+        assert_eq!(product, expected);
+    }
+
+    #[test]
+    fn should_skip_ntt_when_unsuitable() {
+        // Too small to be worth it
+        assert!(ntt_mul(&sample(4, 1), &sample(200, 2)).is_none());
+
+        // bn254 Fq cannot hold a product of 256 coefficients
+        let a: Vec<Fq> = (0..128u64).map(Fq::from).collect();
+        assert!(ntt_mul(&a, &a).is_none());
+    }
+}
@@ -0,0 +1,140 @@
+// Euclid's algorithm over F[x], built on div_rem.
+// Results are normalized to be monic so that the gcd is unique.
+
+use ark_ff::PrimeField;
+
+use super::{PolynomialError, Polynomials};
+
+impl<F: PrimeField> Polynomials<F> {
+    // Scales the polynomial so its leading coefficient is 1, zero stays zero
+    pub fn monic(&self) -> Polynomials<F> {
+        match self.leading_coefficient() {
+            Some(leading) => self.scalar_mul(leading.inverse().expect("The leading coefficient is never zero")),
+            None => self.clone(),
+        }
+    }
+
+    pub fn is_monic(&self) -> bool {
+        self.leading_coefficient() == Some(F::one())
+    }
+
+    // Monic greatest common divisor, gcd(0, 0) = 0
+    pub fn gcd(&self, other: &Polynomials<F>) -> Polynomials<F> {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let remainder = a.try_rem(&b).expect("The divisor is never zero");
+            a = b;
+            b = remainder;
+        }
+        a.monic()
+    }
+
+    // Extended Euclid, returns (g, s, t) with s * self + t * other = g = gcd(self, other)
+    pub fn xgcd(&self, other: &Polynomials<F>) -> (Polynomials<F>, Polynomials<F>, Polynomials<F>) {
+        let (mut r0, mut r1) = (self.clone(), other.clone());
+        let (mut s0, mut s1) = (Polynomials::from_coefficients(vec![F::one()]), Polynomials::zero());
+        let (mut t0, mut t1) = (Polynomials::zero(), Polynomials::from_coefficients(vec![F::one()]));
+
+        while !r1.is_zero() {
+            let (quotient, remainder) = r0.div_rem(&r1).expect("The divisor is never zero");
+            let s2 = &s0 - &(&quotient * &s1);
+            let t2 = &t0 - &(&quotient * &t1);
+
+            (r0, r1) = (r1, remainder);
+            (s0, s1) = (s1, s2);
+            (t0, t1) = (t1, t2);
+        }
+
+        // Scale the Bezout identity so the gcd is monic
+        match r0.leading_coefficient() {
+            Some(leading) => {
+                let leading_inv = leading.inverse().expect("The leading coefficient is never zero");
+                (r0.scalar_mul(leading_inv), s0.scalar_mul(leading_inv), t0.scalar_mul(leading_inv))
+            }
+            None => (r0, s0, t0),
+        }
+    }
+
+    // The polynomial g with self * g = 1 mod modulus, with deg(g) < deg(modulus).
+    // Fails when self and modulus share a factor.
+    pub fn inverse_mod(&self, modulus: &Polynomials<F>) -> Result<Polynomials<F>, PolynomialError> {
+        let reduced = self.try_rem(modulus)?;
+        // Everything is 0 modulo a constant
+        if modulus.degree() == Some(0) {
+            return Ok(Polynomials::zero());
+        }
+
+        let (gcd, s, _) = reduced.xgcd(modulus);
+        if gcd.degree() != Some(0) {
+            return Err(PolynomialError::NotInvertible);
+        }
+
+        s.try_rem(modulus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fq;
+    use ark_ff::{AdditiveGroup, Field};
+
+    use super::*;
+    use crate::univariate::mocks::poly;
+
+    #[test]
+    fn should_compute_monic_gcd() {
+        // (x - 1)(x + 2) = x^2 + x - 2 and 3(x - 1)(x - 3) = 3x^2 - 12x + 9
+        let a = poly::<Fq>(&[-2, 1, 1]);
+        let b = poly(&[9, -12, 3]);
+
+        // x - 1
+        let gcd = a.gcd(&b);
+        assert_eq!(gcd, poly(&[-1, 1]));
+        assert!(gcd.is_monic());
+        assert_eq!(b.gcd(&a), gcd);
+
+        // Coprime polynomials have gcd 1
+        assert_eq!(a.gcd(&poly(&[5, 1])), poly(&[1]));
+        // gcd(f, 0) is f made monic
+        assert_eq!(b.gcd(&Polynomials::zero()), poly(&[3, -4, 1]));
+        assert!(Polynomials::<Fq>::zero().gcd(&Polynomials::zero()).is_zero());
+    }
+
+    #[test]
+    fn should_compute_bezout_coefficients() {
+        // 3x^5 + 5x^3 - 7x - 5 and x^3 + 9x^2 - 9
+        let a = poly::<Fq>(&[-5, -7, 0, 5, 0, 3]);
+        let b = poly(&[-9, 0, 9, 1]);
+
+        let (gcd, s, t) = a.xgcd(&b);
+        assert_eq!(gcd, a.gcd(&b));
+        assert_eq!(&(&s * &a) + &(&t * &b), gcd);
+
+        // With a common factor (x - 1)
+        let c = &a * &poly(&[-1, 1]);
+        let d = &b * &poly(&[-1, 1]);
+        let (gcd, s, t) = c.xgcd(&d);
+        assert_eq!(gcd.degree(), Some(1));
+        assert_eq!(&(&s * &c) + &(&t * &d), gcd);
+    }
+
+    #[test]
+    fn should_invert_modulo_a_polynomial() {
+        // x^2 + 1 is irreducible when -1 is not a square, which holds for bn254 Fq
+        let modulus = poly(&[1, 0, 1]);
+        // x + 3
+        let f = poly(&[3, 1]);
+
+        let inverse = f.inverse_mod(&modulus).unwrap();
+        assert!(inverse.degree().unwrap() < 2);
+        assert_eq!((&f * &inverse).try_rem(&modulus).unwrap(), poly(&[1]));
+        // (x + 3)(-x + 3) = 9 - x^2 = 10 mod x^2 + 1
+        assert_eq!(inverse, poly(&[3, -1]).scalar_mul(Fq::from(10).inverse().unwrap()));
+
+        // x - 1 divides x^2 - 1
+        let modulus = poly(&[-1, 0, 1]);
+        assert_eq!(poly(&[-1, 1]).inverse_mod(&modulus), Err(PolynomialError::NotInvertible));
+        assert_eq!(f.inverse_mod(&Polynomials::zero()), Err(PolynomialError::DivisionByZero));
+        assert_eq!(Polynomials::new(vec![Fq::ZERO]).unwrap().inverse_mod(&modulus), Err(PolynomialError::NotInvertible));
+    }
+}
@@ -0,0 +1,196 @@
+// Barycentric Lagrange interpolation over a fixed set of x-coordinates.
+// With l(x) = prod_k (x - x_k) and the weights w_j = 1 / prod_{k != j} (x_j - x_k),
+// the interpolant through (x_j, y_j) is
+//     f(x) = sum_j y_j * w_j * l(x) / (x - x_j)
+// The weights only depend on the x-coordinates, so they are computed once
+// and reused for every set of y-values.
+
+use ark_ff::PrimeField;
+
+use super::{PolynomialError, Polynomials};
+
+#[derive(Debug, Clone)]
+pub struct LagrangeInterpolator<F: PrimeField> {
+    xs: Vec<F>,
+    weights: Vec<F>,
+    // l(x) = prod_k (x - x_k)
+    vanishing: Polynomials<F>,
+}
+
+impl<F: PrimeField> LagrangeInterpolator<F> {
+    // O(n^2) multiplications and a single field inversion
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(points = xs.len())))]
+    pub fn new(xs: Vec<F>) -> Result<Self, PolynomialError> {
+        if xs.is_empty() {
+            return Err(PolynomialError::EmptyInput);
+        }
+
+        let mut weights: Vec<F> = xs
+            .iter()
+            .enumerate()
+            .map(|(j, xj)| {
+                xs.iter()
+                    .enumerate()
+                    .filter(|(k, _)| *k != j)
+                    .fold(F::one(), |acc, (_, xk)| acc * (*xj - xk))
+            })
+            .collect();
+        // A zero product means two x-coordinates are equal
+        batch_inverse(&mut weights).map_err(|_| PolynomialError::DuplicatePoints)?;
+
+        let vanishing = xs
+            .iter()
+            .map(|x| Polynomials::from_coefficients(vec![-*x, F::one()]))
+            .product();
+
+        Ok(LagrangeInterpolator { xs, weights, vanishing })
+    }
+
+    pub fn xs(&self) -> &Vec<F> {
+        &self.xs
+    }
+
+    pub fn weights(&self) -> &Vec<F> {
+        &self.weights
+    }
+
+    // Coefficients of the interpolant through (x_j, ys[j]) in O(n^2)
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(points = ys.len())))]
+    pub fn interpolate(&self, ys: &[F]) -> Result<Polynomials<F>, PolynomialError> {
+        self.check_length(ys)?;
+
+        let mut coefficients = vec![F::zero(); self.xs.len()];
+        for ((x, weight), y) in self.xs.iter().zip(self.weights.iter()).zip(ys.iter()) {
+            if y.is_zero() {
+                continue;
+            }
+
+            // l(x) / (x - x_j) is exact, the remainder l(x_j) is zero
+            let (basis, _) = self.vanishing.div_by_linear(*x);
+            let scale = *y * weight;
+            for (coefficient, value) in coefficients.iter_mut().zip(basis.coefficients().iter()) {
+                *coefficient += scale * value;
+            }
+        }
+
+        Ok(Polynomials::from_coefficients(coefficients))
+    }
+
+    // Value of the interpolant at `point` in O(n) without building coefficients,
+    // f(x) = sum_j (w_j / (x - x_j)) y_j / sum_j (w_j / (x - x_j))
+    pub fn evaluate(&self, ys: &[F], point: F) -> Result<F, PolynomialError> {
+        self.check_length(ys)?;
+
+        // The formula is undefined on the nodes themselves
+        if let Some(position) = self.xs.iter().position(|x| *x == point) {
+            return Ok(ys[position]);
+        }
+
+        let mut terms: Vec<F> = self.xs.iter().map(|x| point - x).collect();
+        batch_inverse(&mut terms)?;
+
+        let mut numerator = F::zero();
+        let mut denominator = F::zero();
+        for ((term, weight), y) in terms.iter().zip(self.weights.iter()).zip(ys.iter()) {
+            let scaled = *term * weight;
+            numerator += scaled * y;
+            denominator += scaled;
+        }
+
+        Ok(numerator * denominator.inverse().ok_or(PolynomialError::DivisionByZero)?)
+    }
+
+    fn check_length(&self, ys: &[F]) -> Result<(), PolynomialError> {
+        if ys.len() != self.xs.len() {
+            return Err(PolynomialError::LengthMismatch {
+                expected: self.xs.len(),
+                found: ys.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+// Montgomery's trick: inverts every value with a single field inversion
+// and 3(n - 1) multiplications. Fails if any value is zero.
+pub fn batch_inverse<F: PrimeField>(values: &mut [F]) -> Result<(), PolynomialError> {
+    // prefix[i] = values[0] * ... * values[i - 1]
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut accumulator = F::one();
+    for value in values.iter() {
+        prefix.push(accumulator);
+        accumulator *= value;
+    }
+
+    let mut inverse = accumulator.inverse().ok_or(PolynomialError::DivisionByZero)?;
+    for (value, prefix) in values.iter_mut().zip(prefix).rev() {
+        let value_inv = inverse * prefix;
+        inverse *= *value;
+        *value = value_inv;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fq;
+    use ark_ff::{Field, One};
+
+    use super::*;
+    use crate::univariate::types::PolynomialTrait;
+
+    #[test]
+    fn should_batch_invert() {
+        let mut values = vec![Fq::from(2), Fq::from(-3), Fq::from(7)];
+        batch_inverse(&mut values).unwrap();
+        assert_eq!(values, vec![Fq::from(2).inverse().unwrap(), Fq::from(-3).inverse().unwrap(), Fq::from(7).inverse().unwrap()]);
+
+        let mut values = vec![Fq::from(2), Fq::from(0)];
+        assert_eq!(batch_inverse(&mut values), Err(PolynomialError::DivisionByZero));
+    }
+
+    #[test]
+    fn should_interpolate_many_y_vectors() {
+        let interpolator = LagrangeInterpolator::new(
+            vec![Fq::one(), Fq::from(2), Fq::from(4), Fq::from(5)]
+        ).unwrap();
+
+        // x^3 + 7x^2 - x + 10
+        let poly = interpolator.interpolate(&[Fq::from(17), Fq::from(44), Fq::from(182), Fq::from(305)]).unwrap();
+        assert_eq!(poly.coefficients(), &vec![Fq::from(10), Fq::from(-1), Fq::from(7), Fq::one()]);
+
+        // 3x + 5 over the same x-coordinates
+        let poly = interpolator.interpolate(&[Fq::from(8), Fq::from(11), Fq::from(17), Fq::from(20)]).unwrap();
+        assert_eq!(poly.coefficients(), &vec![Fq::from(5), Fq::from(3)]);
+    }
+
+    #[test]
+    fn should_evaluate_without_coefficients() {
+        let xs = vec![Fq::from(3), Fq::from(-1), Fq::from(8), Fq::from(10), Fq::from(11)];
+        let ys = vec![Fq::from(4), Fq::from(9), Fq::from(-2), Fq::from(0), Fq::from(6)];
+        let interpolator = LagrangeInterpolator::new(xs.clone()).unwrap();
+        let poly = interpolator.interpolate(&ys).unwrap();
+
+        for x in [Fq::from(0), Fq::from(5), Fq::from(-7), Fq::from(100)] {
+            assert_eq!(interpolator.evaluate(&ys, x).unwrap(), poly.evaluate(x));
+        }
+        // The nodes return the given values
+        assert_eq!(interpolator.evaluate(&ys, Fq::from(8)).unwrap(), Fq::from(-2));
+    }
+
+    #[test]
+    fn should_reject_invalid_points() {
+        assert_eq!(LagrangeInterpolator::<Fq>::new(vec![]).unwrap_err(), PolynomialError::EmptyInput);
+        assert_eq!(
+            LagrangeInterpolator::new(vec![Fq::from(1), Fq::from(2), Fq::from(1)]).unwrap_err(),
+            PolynomialError::DuplicatePoints
+        );
+
+        let interpolator = LagrangeInterpolator::new(vec![Fq::from(1), Fq::from(2)]).unwrap();
+        assert_eq!(
+            interpolator.interpolate(&[Fq::from(1)]),
+            Err(PolynomialError::LengthMismatch { expected: 2, found: 1 })
+        );
+    }
+}
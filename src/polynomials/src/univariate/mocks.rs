@@ -0,0 +1,16 @@
+use ark_ff::{Fp64, MontBackend, MontConfig, PrimeField};
+
+use super::Polynomials;
+
+// A small prime field for tests where the characteristic matters,
+// e.g. exponents divisible by p or enumerating every field element.
+#[derive(MontConfig)]
+#[modulus = "17"]
+#[generator = "3"]
+pub struct F17Config;
+pub type F17 = Fp64<MontBackend<F17Config, 1>>;
+
+// The polynomial with the given small signed coefficients, constant term first
+pub fn poly<F: PrimeField>(coefficients: &[i64]) -> Polynomials<F> {
+    Polynomials::new(coefficients.iter().map(|c| F::from(*c)).collect()).unwrap()
+}
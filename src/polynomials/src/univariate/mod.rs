@@ -1,31 +1,63 @@
 use std::vec;
 
-use ark_ff::{One, PrimeField, Zero};
+use ark_ff::PrimeField;
+use lagrange::LagrangeInterpolator;
+use parallel::collect_indexed;
 use types::PolynomialTrait;
 
 pub mod types;
+pub mod fft;
+pub mod domain;
+pub mod lagrange;
+pub mod subproduct;
+pub mod sparse;
+pub mod mocks;
 mod arithmetics;
+mod division;
+mod gcd;
+mod composition;
+mod calculus;
+mod roots;
+mod factor;
+mod display;
+mod parallel;
+mod vanishing;
 
-#[derive(Debug)]
-pub enum PolynomialError {
-    DegreeError,
-}
+pub use crate::error::PolynomialError;
 
 //How would we implement a Polynomial type in Rust?
 // define an struct tuple (List of coefficients, degree)
 // Smallest coeffifient first 
 // (ax^n + bx^(n-1) + ... cx + d) (d, c, ... , b, a)
-#[derive(Default, Debug, Clone)]
+// The coefficients are kept canonical: zeros of the highest powers are trimmed,
+// so the zero polynomial is the single coefficient [0].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Polynomials<F: PrimeField>(Vec<F>);
 
 impl<F: PrimeField> Polynomials<F> {
     pub fn new(coefficients: Vec<F>) -> Result<Self, PolynomialError> {
         // The length of the coefficients must be greater than 1
-        if coefficients.len() <= 0 {
-            return Err(PolynomialError::DegreeError);
+        if coefficients.is_empty() {
+            return Err(PolynomialError::EmptyInput);
         }
 
-        Ok(Self(coefficients))
+        Ok(Self::from_coefficients(coefficients))
+    }
+
+    // Infallible constructor for the operations in this crate,
+    // an empty vector is read as the zero polynomial.
+    pub(crate) fn from_coefficients(mut coefficients: Vec<F>) -> Self {
+        let length = trim_zeros(&coefficients).len();
+        if length == 0 {
+            return Self::zero();
+        }
+        coefficients.truncate(length);
+
+        Self(coefficients)
+    }
+
+    pub fn zero() -> Self {
+        Self(vec![F::zero()])
     }
 
     // Returns the list of coefficients only
@@ -33,82 +65,68 @@ impl<F: PrimeField> Polynomials<F> {
         &self.0
     }
 
-    // Returns a more optimal value of the degree by elimination
-    fn degree(&self) -> u8 {
-        // Returns the first degree where the coefficient is not 0
-        let mut degree = (self.coefficients().len() - 1) as u8;
+    pub fn is_zero(&self) -> bool {
+        self.0.len() == 1 && self.0[0].is_zero()
+    }
 
-        // For each coefficient, reduce degree till encounter non-zero
-        for i in 0..degree {
-            if self.coefficients()[i as usize] != Zero::zero() {
-                break;
-            } else {
-                degree -= 1;
-            }
+    // Highest power with a non-zero coefficient, None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        if self.is_zero() {
+            return None;
         }
+        Some(self.0.len() - 1)
+    }
 
-        return degree
+    // Coefficient of the highest power, None for the zero polynomial
+    pub fn leading_coefficient(&self) -> Option<F> {
+        if self.is_zero() {
+            return None;
+        }
+        self.0.last().copied()
     }
 
     pub fn scalar_mul(&self, scalar: F) -> Polynomials<F> {
-        Polynomials::new(self.coefficients().iter().map(|&x| (x * scalar)).collect()).unwrap()
+        let coefficients = self.coefficients();
+        Polynomials::from_coefficients(collect_indexed(coefficients.len(), |i| coefficients[i] * scalar))
     }
 
-    fn from_points(points: Vec<F>, x: F) -> (Self, F) {
-        let numerator = points
-            .iter()
-            .filter(|&xi| x != *xi)
-            .map(|&xi| Polynomials::new(vec![-xi, One::one()])
-            .unwrap()).collect::<Vec<Polynomials<F>>>();
-        let denominator = points
-            .into_iter()
-            .filter(|&xi| x != xi)
-            .map(|xi| x - xi)
-            .reduce(|acc, curr| acc * curr)
-            .unwrap();
-
-        let mut polynomial: Polynomials<F> = Polynomials::new(vec![One::one()]).unwrap();
-        for poly in numerator.iter() {
-            polynomial = &polynomial * poly;
-        }
-        // Multiply all the numerators
-        (polynomial, denominator)
+    // Evaluates at every point, in parallel for many points with the `rayon` feature
+    pub fn evaluate_batch(&self, points: &[F]) -> Vec<F> {
+        collect_indexed(points.len(), |i| self.evaluate(points[i]))
     }
 }
 
+impl<F: PrimeField> Default for Polynomials<F> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+// Drops the zero coefficients of the highest powers
+pub(crate) fn trim_zeros<F: PrimeField>(coefficients: &[F]) -> &[F] {
+    let length = coefficients
+        .iter()
+        .rposition(|coefficient| !coefficient.is_zero())
+        .map_or(0, |position| position + 1);
+    &coefficients[..length]
+}
+
 impl<F: PrimeField> PolynomialTrait<F> for Polynomials<F> {
+    // Horner's rule, (((a_n x + a_(n-1)) x + ...) x + a_0
     fn evaluate(&self, x: F) -> F {
-        let mut result = Zero::zero();
-        let mut power = F::one();
-
-        // Do a to_vec to pass ownership
-        let coefficients = self.coefficients().to_vec();
-        // reverse for more optimized manipulation
-        
-        for (_, coeff) in coefficients.iter().enumerate() {
-            println!("Power of x, {:?}; coefficient {}", power, coeff);
-            result += *coeff * power;
-            power = power * x;
-        }
-
-        result
+        self.coefficients()
+            .iter()
+            .rev()
+            .fold(F::zero(), |result, coefficient| result * x + coefficient)
     }
 
     // Should take an array of points of variable length
     // Point = (x: u32, y: u32)
-    // The resulting polynomial cpefficient are rounded to the nearest integer
-    fn interpolate(points: Vec<(F, F)>) -> Self {
-        let mut interpolated_poly = Polynomials::new(vec![Zero::zero()]).unwrap();
-
-        for (x, y) in points.iter() {
-            let (numerator, denominator) = Polynomials::from_points( 
-                points.iter().map(|(x, _)| *x).collect(), *x);
-                
-            interpolated_poly = &interpolated_poly + &numerator.scalar_mul(*y / denominator);
-        }
+    // Fails on an empty input or when two points share an x-coordinate.
+    fn try_interpolate(points: Vec<(F, F)>) -> Result<Self, PolynomialError> {
+        let (xs, ys): (Vec<F>, Vec<F>) = points.into_iter().unzip();
 
-        // Placeholder for interpolation logic
-        interpolated_poly
+        LagrangeInterpolator::new(xs)?.interpolate(&ys)
     }
 }
 
@@ -116,7 +134,7 @@ impl<F: PrimeField> PolynomialTrait<F> for Polynomials<F> {
 #[cfg(test)]
 mod tests {
     use ark_bn254::Fq;
-    use ark_ff::{AdditiveGroup, Field};
+    use ark_ff::{AdditiveGroup, Field, One, Zero};
 
     use super::*;
 
@@ -149,14 +167,47 @@ mod tests {
         let poly = Polynomials::new(
             vec![Fq::from(5), Fq::from(3)]
         ).unwrap();
-        assert_eq!(poly.degree(), 1);
+        assert_eq!(poly.degree(), Some(1));
 
         // 3x^5 + 5x^3 - 7x - 5
         let poly_result = Polynomials::new(
             vec![Fq::from(-5), Fq::from(-7), Fq::ZERO, Fq::from(5), Fq::zero(), Fq::from(3)]
         );
         let poly = poly_result.unwrap();
-        assert_eq!(poly.degree(), 5);
+        assert_eq!(poly.degree(), Some(5));
+        assert_eq!(poly.leading_coefficient(), Some(Fq::from(3)));
+
+        // x^2, the zero coefficients of the lower powers are kept
+        let poly = Polynomials::new(
+            vec![Fq::ZERO, Fq::ZERO, Fq::ONE]
+        ).unwrap();
+        assert_eq!(poly.degree(), Some(2));
+
+        // The zero polynomial has no degree
+        let poly = Polynomials::new(
+            vec![Fq::ZERO, Fq::ZERO]
+        ).unwrap();
+        assert!(poly.is_zero());
+        assert_eq!(poly.degree(), None);
+        assert_eq!(poly.leading_coefficient(), None);
+    }
+
+    #[test]
+    fn should_trim_high_order_zeros() {
+        // 5 + 0x + 0x^2 is just 5
+        let poly = Polynomials::new(
+            vec![Fq::from(5), Fq::ZERO, Fq::ZERO]
+        ).unwrap();
+        assert_eq!(poly.coefficients(), &vec![Fq::from(5)]);
+        assert_eq!(poly, Polynomials::new(vec![Fq::from(5)]).unwrap());
+        assert_eq!(poly.degree(), Some(0));
+
+        assert_eq!(Polynomials::new(vec![Fq::ZERO; 4]).unwrap(), Polynomials::zero());
+        assert_eq!(Polynomials::<Fq>::default(), Polynomials::zero());
+
+        // (x + 1) - (x + 1) cancels out
+        let poly = Polynomials::new(vec![Fq::ONE, Fq::ONE]).unwrap();
+        assert!((&poly - &poly).is_zero());
     }
 
     #[test]
@@ -187,5 +238,41 @@ mod tests {
             (Fq::from(5), Fq::from(305))];
         let poly = Polynomials::interpolate(points);
         assert_eq!(poly.coefficients(), &vec![Fq::from(10), Fq::from(-1), Fq::from(7), Fq::one()]);
+
+        // A single point gives a constant
+        let poly = Polynomials::interpolate(vec![(Fq::from(3), Fq::from(8))]);
+        assert_eq!(poly.coefficients(), &vec![Fq::from(8)]);
+    }
+
+    #[test]
+    fn should_reject_invalid_input() {
+        assert_eq!(Polynomials::<Fq>::new(vec![]), Err(PolynomialError::EmptyInput));
+        assert_eq!(Polynomials::<Fq>::try_interpolate(vec![]), Err(PolynomialError::EmptyInput));
+
+        let points = vec![
+            (Fq::ONE, Fq::from(17)),
+            (Fq::from(2), Fq::from(44)),
+            (Fq::ONE, Fq::from(20))];
+        assert_eq!(Polynomials::try_interpolate(points), Err(PolynomialError::DuplicatePoints));
+    }
+
+    #[test]
+    fn should_evaluate_with_horner() {
+        // 3x^5 + 5x^3 - 7x - 5 at x = 2 is 96 + 40 - 14 - 5
+        let poly = Polynomials::new(
+            vec![Fq::from(-5), Fq::from(-7), Fq::ZERO, Fq::from(5), Fq::ZERO, Fq::from(3)]
+        ).unwrap();
+        assert_eq!(poly.evaluate(Fq::from(2)), Fq::from(117));
+        assert_eq!(poly.evaluate(Fq::ZERO), Fq::from(-5));
+        assert_eq!(Polynomials::<Fq>::zero().evaluate(Fq::from(9)), Fq::ZERO);
+
+        let points = vec![Fq::from(2), Fq::ZERO, Fq::from(-1)];
+        assert_eq!(poly.evaluate_batch(&points), vec![Fq::from(117), Fq::from(-5), Fq::from(-6)]);
+        assert!(poly.evaluate_batch(&[]).is_empty());
+
+        // Enough points for the parallel path with the rayon feature
+        let points: Vec<Fq> = (0..5000u64).map(Fq::from).collect();
+        let expected: Vec<Fq> = points.iter().map(|x| poly.evaluate(*x)).collect();
+        assert_eq!(poly.evaluate_batch(&points), expected);
     }
-}
\ No newline at end of file
+}
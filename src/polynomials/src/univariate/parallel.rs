@@ -0,0 +1,17 @@
+// Coefficient and evaluation loops that run on the rayon thread pool
+// when the `rayon` feature is enabled and the input is large.
+
+// Below this many outputs the loops stay sequential, the pool overhead outweighs the work
+#[cfg(feature = "rayon")]
+pub(crate) const PARALLEL_THRESHOLD: usize = 1 << 12;
+
+// [f(0), ..., f(len - 1)]
+pub(crate) fn collect_indexed<T: Send>(len: usize, f: impl Fn(usize) -> T + Sync + Send) -> Vec<T> {
+    #[cfg(feature = "rayon")]
+    if len >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+        return (0..len).into_par_iter().map(f).collect();
+    }
+
+    (0..len).map(f).collect()
+}
@@ -0,0 +1,129 @@
+// Root finding over the prime field F_p.
+// Every element of F_p is a root of x^p - x, so g = gcd(f, x^p - x) is the product of
+// the distinct linear factors of f, which Cantor–Zassenhaus then splits one by one.
+
+use ark_ff::{BitIteratorBE, PrimeField};
+use rand::Rng;
+
+use super::{factor::equal_degree_split, PolynomialError, Polynomials};
+
+impl<F: PrimeField> Polynomials<F> {
+    // self^exponent mod modulus by square-and-multiply,
+    // the exponent is given as little-endian u64 limbs like F::MODULUS
+    pub fn pow_mod(&self, exponent: &[u64], modulus: &Polynomials<F>) -> Result<Polynomials<F>, PolynomialError> {
+        let base = self.try_rem(modulus)?;
+        let mut result = Polynomials::from_coefficients(vec![F::one()]).try_rem(modulus)?;
+
+        for bit in BitIteratorBE::without_leading_zeros(exponent) {
+            result = (&result * &result).try_rem(modulus)?;
+            if bit {
+                result = (&result * &base).try_rem(modulus)?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    // All roots in F with their multiplicities, sorted by root.
+    // The zero polynomial vanishes everywhere and returns no roots.
+    pub fn roots(&self) -> Vec<(F, usize)> {
+        self.roots_with_rng(&mut rand::rng())
+    }
+
+    // Same as roots, with the randomness of the splitting step taken from `rng`
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(degree = ?self.degree())))]
+    pub fn roots_with_rng<R: Rng>(&self, rng: &mut R) -> Vec<(F, usize)> {
+        match self.degree() {
+            None | Some(0) => return vec![],
+            Some(_) => {}
+        }
+
+        let mut distinct = vec![];
+        equal_degree_split(&self.linear_part(), 1, rng, &mut distinct);
+        let mut distinct: Vec<F> = distinct.iter().map(|factor| -factor.coefficients()[0]).collect();
+        distinct.sort();
+
+        distinct
+            .into_iter()
+            .map(|root| {
+                // Divide out (x - root) for as long as it is a factor
+                let mut multiplicity = 0;
+                let mut quotient = self.clone();
+                loop {
+                    let (next, remainder) = quotient.div_by_linear(root);
+                    if !remainder.is_zero() {
+                        break;
+                    }
+                    multiplicity += 1;
+                    quotient = next;
+                }
+                (root, multiplicity)
+            })
+            .collect()
+    }
+
+    // gcd(self, x^p - x), the monic product of (x - r) over the distinct roots r
+    pub(crate) fn linear_part(&self) -> Polynomials<F> {
+        let x = Polynomials::from_coefficients(vec![F::zero(), F::one()]);
+        let x_p = x.pow_mod(F::MODULUS.as_ref(), self).expect("The polynomial is never zero");
+
+        self.gcd(&(&x_p - &x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::{Fq, Fr};
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+    use crate::univariate::mocks::{poly, F17};
+
+    #[test]
+    fn should_raise_to_a_power_modulo_a_polynomial() {
+        // x^5 mod x^2 + 1 = x
+        let x = poly::<Fq>(&[0, 1]);
+        assert_eq!(x.pow_mod(&[5], &poly(&[1, 0, 1])).unwrap(), x);
+        assert_eq!(x.pow_mod(&[0], &poly(&[1, 0, 1])).unwrap(), poly(&[1]));
+        assert_eq!(x.pow_mod(&[2], &Polynomials::zero()), Err(PolynomialError::DivisionByZero));
+    }
+
+    #[test]
+    fn should_find_roots_with_multiplicities() {
+        let mut rng = StdRng::seed_from_u64(7);
+
+        // (x - 1)^2 (x + 3) (x^2 + 1), x^2 + 1 has no roots in Fq
+        let f = &(&(&poly(&[-1, 1]) * &poly(&[-1, 1])) * &poly(&[3, 1])) * &poly(&[1, 0, 1]);
+        assert_eq!(f.roots_with_rng(&mut rng), vec![(Fq::from(1), 2), (Fq::from(-3), 1)]);
+
+        assert_eq!(poly::<Fq>(&[1, 0, 1]).roots_with_rng(&mut rng), vec![]);
+        assert_eq!(poly::<Fq>(&[5]).roots_with_rng(&mut rng), vec![]);
+        assert_eq!(Polynomials::<Fq>::zero().roots_with_rng(&mut rng), vec![]);
+    }
+
+    #[test]
+    fn should_recover_the_points_of_a_vanishing_polynomial() {
+        let mut xs: Vec<Fr> = (0..20u64).map(|i| Fr::from(i * i * 31 + 5)).collect();
+        let vanishing: Polynomials<Fr> = xs
+            .iter()
+            .map(|x| Polynomials::new(vec![-*x, Fr::from(1)]).unwrap())
+            .product();
+
+        let roots = vanishing.roots_with_rng(&mut StdRng::seed_from_u64(1));
+        xs.sort();
+        assert_eq!(roots, xs.into_iter().map(|x| (x, 1)).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn should_find_every_element_of_a_small_field() {
+        // x^17 - x vanishes on all of F17
+        let mut coefficients = vec![F17::from(0u64); 18];
+        coefficients[1] = F17::from(-1);
+        coefficients[17] = F17::from(1u64);
+        let f = Polynomials::new(coefficients).unwrap();
+
+        let roots = f.roots_with_rng(&mut StdRng::seed_from_u64(3));
+        assert_eq!(roots, (0..17u64).map(|i| (F17::from(i), 1)).collect::<Vec<_>>());
+        assert_eq!(f.roots(), roots);
+    }
+}
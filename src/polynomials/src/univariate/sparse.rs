@@ -0,0 +1,341 @@
+// Sparse univariate polynomials, stored as (exponent, coefficient) pairs.
+// Polynomials with few non-zero terms but a huge degree, like the vanishing polynomial
+// x^(2^20) - 1 or circuit selectors, cost memory proportional to the number of terms
+// instead of the degree.
+
+use std::{
+    collections::BTreeMap,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use ark_ff::PrimeField;
+
+use super::{types::PolynomialTrait, PolynomialError, Polynomials};
+
+// The terms are kept canonical: sorted by exponent, one term per exponent
+// and no zero coefficients, so the zero polynomial has no terms.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SparsePolynomial<F: PrimeField>(Vec<(usize, F)>);
+
+impl<F: PrimeField> SparsePolynomial<F> {
+    // Terms sharing an exponent are added together
+    pub fn new(terms: Vec<(usize, F)>) -> Self {
+        let mut combined = BTreeMap::new();
+        for (exponent, coefficient) in terms {
+            *combined.entry(exponent).or_insert_with(F::zero) += coefficient;
+        }
+
+        Self::from_map(combined)
+    }
+
+    fn from_map(terms: BTreeMap<usize, F>) -> Self {
+        Self(terms.into_iter().filter(|(_, coefficient)| !coefficient.is_zero()).collect())
+    }
+
+    pub fn zero() -> Self {
+        Self(vec![])
+    }
+
+    // The (exponent, coefficient) pairs in increasing exponent order
+    pub fn terms(&self) -> &Vec<(usize, F)> {
+        &self.0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // Highest power with a non-zero coefficient, None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.0.last().map(|(exponent, _)| *exponent)
+    }
+
+    pub fn scalar_mul(&self, scalar: F) -> SparsePolynomial<F> {
+        if scalar.is_zero() {
+            return Self::zero();
+        }
+        Self(self.0.iter().map(|(exponent, coefficient)| (*exponent, *coefficient * scalar)).collect())
+    }
+
+    // The dense form, allocates degree + 1 coefficients
+    pub fn to_dense(&self) -> Polynomials<F> {
+        let mut coefficients = vec![F::zero(); self.degree().map_or(0, |degree| degree + 1)];
+        for (exponent, coefficient) in self.0.iter() {
+            coefficients[*exponent] = *coefficient;
+        }
+        Polynomials::from_coefficients(coefficients)
+    }
+}
+
+impl<F: PrimeField> Default for SparsePolynomial<F> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<F: PrimeField> PolynomialTrait<F> for SparsePolynomial<F> {
+    // Each power of x comes from the previous one by square-and-multiply on the gap,
+    // O(k log d) multiplications for k terms of degree at most d
+    fn evaluate(&self, x: F) -> F {
+        let mut result = F::zero();
+        let mut power = F::one();
+        let mut previous = 0;
+
+        for (exponent, coefficient) in self.0.iter() {
+            power *= x.pow([(*exponent - previous) as u64]);
+            previous = *exponent;
+            result += *coefficient * power;
+        }
+
+        result
+    }
+
+    // Interpolates densely and keeps the non-zero coefficients
+    fn try_interpolate(points: Vec<(F, F)>) -> Result<Self, PolynomialError> {
+        Polynomials::try_interpolate(points).map(SparsePolynomial::from)
+    }
+}
+
+impl<F: PrimeField> From<&Polynomials<F>> for SparsePolynomial<F> {
+    fn from(poly: &Polynomials<F>) -> Self {
+        Self(
+            poly.coefficients()
+                .iter()
+                .enumerate()
+                .filter(|(_, coefficient)| !coefficient.is_zero())
+                .map(|(exponent, coefficient)| (exponent, *coefficient))
+                .collect(),
+        )
+    }
+}
+
+impl<F: PrimeField> From<Polynomials<F>> for SparsePolynomial<F> {
+    fn from(poly: Polynomials<F>) -> Self {
+        Self::from(&poly)
+    }
+}
+
+impl<F: PrimeField> From<&SparsePolynomial<F>> for Polynomials<F> {
+    fn from(poly: &SparsePolynomial<F>) -> Self {
+        poly.to_dense()
+    }
+}
+
+impl<F: PrimeField> From<SparsePolynomial<F>> for Polynomials<F> {
+    fn from(poly: SparsePolynomial<F>) -> Self {
+        poly.to_dense()
+    }
+}
+
+impl<F: PrimeField> Add for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn add(self, other: &SparsePolynomial<F>) -> SparsePolynomial<F> {
+        let mut terms: BTreeMap<usize, F> = self.0.iter().copied().collect();
+        for (exponent, coefficient) in other.0.iter() {
+            *terms.entry(*exponent).or_insert_with(F::zero) += coefficient;
+        }
+        SparsePolynomial::from_map(terms)
+    }
+}
+
+impl<F: PrimeField> Neg for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn neg(self) -> SparsePolynomial<F> {
+        SparsePolynomial(self.0.iter().map(|(exponent, coefficient)| (*exponent, -*coefficient)).collect())
+    }
+}
+
+impl<F: PrimeField> Neg for SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn neg(self) -> SparsePolynomial<F> {
+        -&self
+    }
+}
+
+impl<F: PrimeField> Sub for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn sub(self, other: &SparsePolynomial<F>) -> SparsePolynomial<F> {
+        self + &(-other)
+    }
+}
+
+// Every pair of terms, O(k1 * k2 log(k1 * k2))
+impl<F: PrimeField> Mul for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn mul(self, other: &SparsePolynomial<F>) -> SparsePolynomial<F> {
+        let mut terms = BTreeMap::new();
+        for (a_exponent, a) in self.0.iter() {
+            for (b_exponent, b) in other.0.iter() {
+                *terms.entry(a_exponent + b_exponent).or_insert_with(F::zero) += *a * b;
+            }
+        }
+        SparsePolynomial::from_map(terms)
+    }
+}
+
+// Mixed arithmetic returns a dense polynomial, the dense operand already
+// holds every coefficient up to its degree
+impl<F: PrimeField> Add<&SparsePolynomial<F>> for &Polynomials<F> {
+    type Output = Polynomials<F>;
+
+    fn add(self, other: &SparsePolynomial<F>) -> Polynomials<F> {
+        let mut coefficients = self.coefficients().clone();
+        if let Some(degree) = other.degree() {
+            coefficients.resize(coefficients.len().max(degree + 1), F::zero());
+        }
+        for (exponent, coefficient) in other.terms().iter() {
+            coefficients[*exponent] += coefficient;
+        }
+        Polynomials::from_coefficients(coefficients)
+    }
+}
+
+impl<F: PrimeField> Add<&Polynomials<F>> for &SparsePolynomial<F> {
+    type Output = Polynomials<F>;
+
+    fn add(self, other: &Polynomials<F>) -> Polynomials<F> {
+        other + self
+    }
+}
+
+impl<F: PrimeField> Sub<&SparsePolynomial<F>> for &Polynomials<F> {
+    type Output = Polynomials<F>;
+
+    fn sub(self, other: &SparsePolynomial<F>) -> Polynomials<F> {
+        self + &(-other)
+    }
+}
+
+impl<F: PrimeField> Sub<&Polynomials<F>> for &SparsePolynomial<F> {
+    type Output = Polynomials<F>;
+
+    fn sub(self, other: &Polynomials<F>) -> Polynomials<F> {
+        &(-other) + self
+    }
+}
+
+// One shifted and scaled copy of the dense operand per sparse term, O(n * k)
+impl<F: PrimeField> Mul<&SparsePolynomial<F>> for &Polynomials<F> {
+    type Output = Polynomials<F>;
+
+    fn mul(self, other: &SparsePolynomial<F>) -> Polynomials<F> {
+        let degree = match (self.degree(), other.degree()) {
+            (Some(a), Some(b)) => a + b,
+            _ => return Polynomials::zero(),
+        };
+
+        let mut coefficients = vec![F::zero(); degree + 1];
+        for (exponent, scalar) in other.terms().iter() {
+            for (index, coefficient) in self.coefficients().iter().enumerate() {
+                coefficients[exponent + index] += *coefficient * scalar;
+            }
+        }
+        Polynomials::from_coefficients(coefficients)
+    }
+}
+
+impl<F: PrimeField> Mul<&Polynomials<F>> for &SparsePolynomial<F> {
+    type Output = Polynomials<F>;
+
+    fn mul(self, other: &Polynomials<F>) -> Polynomials<F> {
+        other * self
+    }
+}
+
+forward_binop!(SparsePolynomial, SparsePolynomial, SparsePolynomial, Add, add);
+forward_binop!(SparsePolynomial, SparsePolynomial, SparsePolynomial, Sub, sub);
+forward_binop!(SparsePolynomial, SparsePolynomial, SparsePolynomial, Mul, mul);
+forward_binop!(Polynomials, SparsePolynomial, Polynomials, Add, add);
+forward_binop!(Polynomials, SparsePolynomial, Polynomials, Sub, sub);
+forward_binop!(Polynomials, SparsePolynomial, Polynomials, Mul, mul);
+forward_binop!(SparsePolynomial, Polynomials, Polynomials, Add, add);
+forward_binop!(SparsePolynomial, Polynomials, Polynomials, Sub, sub);
+forward_binop!(SparsePolynomial, Polynomials, Polynomials, Mul, mul);
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::{Fq, Fr};
+    use ark_ff::{Field, One};
+
+    use super::*;
+    use crate::univariate::{fft::root_of_unity, mocks::poly};
+
+    fn sparse(terms: &[(usize, i64)]) -> SparsePolynomial<Fq> {
+        SparsePolynomial::new(terms.iter().map(|(exponent, c)| (*exponent, Fq::from(*c))).collect())
+    }
+
+    #[test]
+    fn should_keep_terms_canonical() {
+        // 3x^5 + 2x^5 - 7x + 0x^2 - 5 + 7x
+        let poly = sparse(&[(5, 3), (5, 2), (1, -7), (2, 0), (0, -5), (1, 7)]);
+        assert_eq!(poly.terms(), &vec![(0, Fq::from(-5)), (5, Fq::from(5))]);
+        assert_eq!(poly.degree(), Some(5));
+
+        assert!(sparse(&[(3, 1), (3, -1)]).is_zero());
+        assert_eq!(SparsePolynomial::<Fq>::zero().degree(), None);
+        assert!(sparse(&[(2, 4)]).scalar_mul(Fq::from(0)).is_zero());
+    }
+
+    #[test]
+    fn should_evaluate_huge_degrees() {
+        // x^(2^20) - 1 vanishes on the subgroup of order 2^20
+        let vanishing = SparsePolynomial::new(vec![(1 << 20, Fr::one()), (0, -Fr::one())]);
+        let omega: Fr = root_of_unity(1 << 20).unwrap();
+        assert_eq!(vanishing.evaluate(omega), Fr::from(0));
+        assert_eq!(vanishing.evaluate(omega.pow([3u64])), Fr::from(0));
+        assert_eq!(vanishing.evaluate(Fr::from(2)), Fr::from(2).pow([1u64 << 20]) - Fr::one());
+
+        // (x^(2^20) - 1)(x^(2^20) + 1) = x^(2^21) - 1
+        let other = SparsePolynomial::new(vec![(1 << 20, Fr::one()), (0, Fr::one())]);
+        assert_eq!(&vanishing * &other, SparsePolynomial::new(vec![(1 << 21, Fr::one()), (0, -Fr::one())]));
+    }
+
+    #[test]
+    fn should_convert_to_and_from_dense() {
+        // 3x^5 + 5x^3 - 7x - 5
+        let dense = poly(&[-5, -7, 0, 5, 0, 3]);
+        let sparse_poly = SparsePolynomial::from(&dense);
+        assert_eq!(sparse_poly, sparse(&[(0, -5), (1, -7), (3, 5), (5, 3)]));
+        assert_eq!(Polynomials::from(&sparse_poly), dense);
+
+        for x in [Fq::from(0), Fq::from(2), Fq::from(-9)] {
+            assert_eq!(sparse_poly.evaluate(x), dense.evaluate(x));
+        }
+
+        assert!(SparsePolynomial::from(Polynomials::<Fq>::zero()).is_zero());
+        assert!(SparsePolynomial::<Fq>::zero().to_dense().is_zero());
+    }
+
+    #[test]
+    fn should_match_dense_arithmetic() {
+        let a = sparse(&[(0, -5), (1, -7), (3, 5), (7, 3)]);
+        let b = sparse(&[(1, 2), (3, -5), (4, 1)]);
+        let (dense_a, dense_b) = (a.to_dense(), b.to_dense());
+
+        assert_eq!((&a + &b).to_dense(), &dense_a + &dense_b);
+        assert_eq!((&a - &b).to_dense(), &dense_a - &dense_b);
+        assert_eq!((&a * &b).to_dense(), &dense_a * &dense_b);
+        assert!((&a - &a).is_zero());
+
+        // Mixed dense and sparse operands
+        assert_eq!(&dense_a + &b, &dense_a + &dense_b);
+        assert_eq!(&b + &dense_a, &dense_a + &dense_b);
+        assert_eq!(&dense_a - &b, &dense_a - &dense_b);
+        assert_eq!(&b - &dense_a, &dense_b - &dense_a);
+        assert_eq!(&dense_a * &b, &dense_a * &dense_b);
+        assert_eq!(b.clone() * dense_a.clone(), &dense_a * &dense_b);
+        assert!((&dense_a * &SparsePolynomial::zero()).is_zero());
+    }
+
+    #[test]
+    fn should_interpolate_into_sparse_form() {
+        // x^3 + 1
+        let points: Vec<(Fq, Fq)> = (0..4i64).map(|x| (Fq::from(x), Fq::from(x * x * x + 1))).collect();
+        assert_eq!(SparsePolynomial::try_interpolate(points).unwrap(), sparse(&[(0, 1), (3, 1)]));
+    }
+}
@@ -0,0 +1,207 @@
+// Subproduct tree over a set of points x_0, ..., x_(n-1).
+// The leaves are the linear factors (x - x_i) and every node is the product of its children,
+// so the root is m(x) = prod_i (x - x_i).
+//
+// - Evaluation walks down the tree: f mod node, then reduce by each child until the leaves,
+//   where f mod (x - x_i) = f(x_i).
+// - Interpolation walks up the tree: f = sum_i c_i * m(x) / (x - x_i) with c_i = y_i / m'(x_i),
+//   combined pairwise as left_sum * right_node + right_sum * left_node.
+//
+// With NTT multiplication and Newton division both are O(n log^2 n).
+
+use ark_ff::PrimeField;
+
+use super::{lagrange::batch_inverse, PolynomialError, Polynomials};
+
+#[derive(Debug, Clone)]
+pub struct SubproductTree<F: PrimeField> {
+    points: Vec<F>,
+    // levels[0] holds the leaves, the last level the root.
+    // Node j of level l + 1 is the product of nodes 2j and 2j + 1 of level l,
+    // an unpaired last node is carried up unchanged.
+    levels: Vec<Vec<Polynomials<F>>>,
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(points = points.len())))]
+    pub fn new(points: &[F]) -> Result<Self, PolynomialError> {
+        if points.is_empty() {
+            return Err(PolynomialError::EmptyInput);
+        }
+
+        let leaves: Vec<Polynomials<F>> = points
+            .iter()
+            .map(|x| Polynomials::from_coefficients(vec![-*x, F::one()]))
+            .collect();
+        let mut levels = vec![leaves];
+
+        while levels[levels.len() - 1].len() > 1 {
+            let next = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => left * right,
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+
+        Ok(SubproductTree {
+            points: points.to_vec(),
+            levels,
+        })
+    }
+
+    pub fn points(&self) -> &Vec<F> {
+        &self.points
+    }
+
+    // m(x) = prod_i (x - x_i)
+    pub fn root(&self) -> &Polynomials<F> {
+        &self.levels[self.levels.len() - 1][0]
+    }
+
+    // [f(x_0), ..., f(x_(n-1))]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(points = self.points.len(), coefficients = poly.coefficients().len())))]
+    pub fn evaluate(&self, poly: &Polynomials<F>) -> Vec<F> {
+        let mut remainders = vec![remainder(poly, self.root())];
+
+        for level in (0..self.levels.len() - 1).rev() {
+            remainders = self.levels[level]
+                .iter()
+                .enumerate()
+                .map(|(index, node)| remainder(&remainders[index / 2], node))
+                .collect();
+        }
+
+        remainders.iter().map(|remainder| remainder.coefficients()[0]).collect()
+    }
+
+    // The unique polynomial of degree < n through (x_i, ys[i])
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(points = ys.len())))]
+    pub fn interpolate(&self, ys: &[F]) -> Result<Polynomials<F>, PolynomialError> {
+        if ys.len() != self.points.len() {
+            return Err(PolynomialError::LengthMismatch {
+                expected: self.points.len(),
+                found: ys.len(),
+            });
+        }
+
+        // m'(x_i) vanishes exactly when x_i is repeated
+        let mut weights = self.evaluate(&self.root().derivative());
+        batch_inverse(&mut weights).map_err(|_| PolynomialError::DuplicatePoints)?;
+
+        let mut sums: Vec<Polynomials<F>> = ys
+            .iter()
+            .zip(weights.iter())
+            .map(|(y, weight)| Polynomials::from_coefficients(vec![*y * weight]))
+            .collect();
+
+        for level in 0..self.levels.len() - 1 {
+            let nodes = &self.levels[level];
+            sums = sums
+                .chunks(2)
+                .enumerate()
+                .map(|(index, pair)| match pair {
+                    [left, right] => &(left * &nodes[2 * index + 1]) + &(right * &nodes[2 * index]),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        Ok(sums.swap_remove(0))
+    }
+}
+
+impl<F: PrimeField> Polynomials<F> {
+    // Evaluates at every point in O(n log^2 n)
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        match SubproductTree::new(points) {
+            Ok(tree) => tree.evaluate(self),
+            Err(_) => vec![],
+        }
+    }
+
+    // Interpolates the points in O(n log^2 n).
+    // Fails on an empty input or when two points share an x-coordinate.
+    pub fn interpolate_fast(points: Vec<(F, F)>) -> Result<Self, PolynomialError> {
+        let (xs, ys): (Vec<F>, Vec<F>) = points.into_iter().unzip();
+
+        SubproductTree::new(&xs)?.interpolate(&ys)
+    }
+}
+
+fn remainder<F: PrimeField>(poly: &Polynomials<F>, divisor: &Polynomials<F>) -> Polynomials<F> {
+    if poly.coefficients().len() < divisor.coefficients().len() {
+        return poly.clone();
+    }
+    poly.try_rem(divisor).expect("Tree nodes are never zero")
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::{Fq, Fr};
+
+    use super::*;
+    use crate::univariate::types::PolynomialTrait;
+
+    #[test]
+    fn should_build_the_tree() {
+        let points = vec![Fq::from(1), Fq::from(2), Fq::from(3)];
+        let tree = SubproductTree::new(&points).unwrap();
+
+        // (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6
+        assert_eq!(tree.root().coefficients(), &vec![Fq::from(-6), Fq::from(11), Fq::from(-6), Fq::from(1)]);
+        assert!(SubproductTree::<Fq>::new(&[]).is_err());
+    }
+
+    #[test]
+    fn should_evaluate_many_points() {
+        // 3x^5 + 5x^3 - 7x - 5
+        let poly = Polynomials::new(
+            vec![Fq::from(-5), Fq::from(-7), Fq::from(0), Fq::from(5), Fq::from(0), Fq::from(3)]
+        ).unwrap();
+        let points: Vec<Fq> = (0..7i64).map(|i| Fq::from(i * i - 10)).collect();
+
+        let expected: Vec<Fq> = points.iter().map(|x| poly.evaluate(*x)).collect();
+        assert_eq!(poly.evaluate_many(&points), expected);
+    }
+
+    #[test]
+    fn should_match_evaluate_on_large_inputs() {
+        // Large enough for the NTT and Newton division paths
+        let poly = Polynomials::new(
+            (0..300u64).map(|i| Fr::from(i * i + 11)).collect()
+        ).unwrap();
+        let points: Vec<Fr> = (0..257u64).map(|i| Fr::from(3 * i + 1)).collect();
+
+        let expected: Vec<Fr> = points.iter().map(|x| poly.evaluate(*x)).collect();
+        assert_eq!(poly.evaluate_many(&points), expected);
+    }
+
+    #[test]
+    fn should_interpolate_fast() {
+        let points = vec![
+            (Fq::from(1), Fq::from(17)),
+            (Fq::from(2), Fq::from(44)),
+            (Fq::from(4), Fq::from(182)),
+            (Fq::from(5), Fq::from(305))];
+        let poly = Polynomials::interpolate_fast(points.clone()).unwrap();
+        assert_eq!(poly, Polynomials::interpolate(points));
+
+        let points: Vec<(Fr, Fr)> = (0..150u64)
+            .map(|i| (Fr::from(5 * i + 2), Fr::from(i * i * i + 7)))
+            .collect();
+        let poly = Polynomials::interpolate_fast(points.clone()).unwrap();
+        assert_eq!(poly, Polynomials::interpolate(points));
+    }
+
+    #[test]
+    fn should_reject_duplicate_points() {
+        let points = vec![(Fq::from(1), Fq::from(2)), (Fq::from(3), Fq::from(4)), (Fq::from(1), Fq::from(5))];
+        assert_eq!(Polynomials::interpolate_fast(points), Err(PolynomialError::DuplicatePoints));
+        assert_eq!(Polynomials::<Fq>::interpolate_fast(vec![]), Err(PolynomialError::EmptyInput));
+    }
+}
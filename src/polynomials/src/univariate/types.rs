@@ -1,7 +1,14 @@
 use ark_ff::PrimeField;
 
-pub trait PolynomialTrait<F: PrimeField> {
+use super::PolynomialError;
+
+pub trait PolynomialTrait<F: PrimeField>: Sized {
     // Gives the value of f(x) at the given value of x;
     fn evaluate(&self, x: F) -> F;
-    fn interpolate(points: Vec<(F, F)>) -> Self;
-}
\ No newline at end of file
+    fn try_interpolate(points: Vec<(F, F)>) -> Result<Self, PolynomialError>;
+
+    // Panics on empty input or duplicate x-coordinates, see try_interpolate
+    fn interpolate(points: Vec<(F, F)>) -> Self {
+        Self::try_interpolate(points).expect("Cannot interpolate the points")
+    }
+}
@@ -0,0 +1,199 @@
+// Vanishing polynomials, zero exactly on a given set of points.
+// For a domain gH of size n, Z(x) = prod_i (x - g w^i) = x^n - g^n,
+// so Z_H(x) = x^n - 1 for the subgroup itself.
+//
+// A constraint f holds on the domain when Z divides f. Over a disjoint coset g'H'
+// of size m >= n, Z(g' w'^i) = g'^n w'^(in) - g^n only takes m / n distinct values,
+// so the quotient f / Z is a pointwise division with m / n inversions.
+
+use ark_ff::PrimeField;
+
+use super::{
+    domain::{Domain, Evaluations},
+    lagrange::batch_inverse,
+    sparse::SparsePolynomial,
+    subproduct::SubproductTree,
+    PolynomialError, Polynomials,
+};
+
+impl<F: PrimeField> Domain<F> {
+    // x^n - g^n
+    pub fn vanishing_polynomial(&self) -> SparsePolynomial<F> {
+        SparsePolynomial::new(vec![(0, -self.vanishing_constant()), (self.size(), F::one())])
+    }
+
+    // Z(x) in O(log n)
+    pub fn evaluate_vanishing(&self, x: F) -> F {
+        x.pow([self.size() as u64]) - self.vanishing_constant()
+    }
+
+    fn vanishing_constant(&self) -> F {
+        self.offset().pow([self.size() as u64])
+    }
+}
+
+impl<F: PrimeField> Polynomials<F> {
+    // prod_i (x - x_i), the constant 1 for no roots.
+    // Repeated roots are kept with their multiplicity.
+    pub fn from_roots(roots: &[F]) -> Polynomials<F> {
+        match SubproductTree::new(roots) {
+            Ok(tree) => tree.root().clone(),
+            Err(_) => Polynomials::from_coefficients(vec![F::one()]),
+        }
+    }
+
+    // f / (x^n - c) in O(deg f) by folding every coefficient a_i (i >= n) into a_(i-n).
+    // Fails unless the polynomial vanishes on the whole domain.
+    pub fn divide_by_vanishing(&self, domain: &Domain<F>) -> Result<Polynomials<F>, PolynomialError> {
+        let size = domain.size();
+        let constant = domain.vanishing_constant();
+
+        let mut remainder = self.coefficients().clone();
+        let mut quotient = vec![F::zero(); remainder.len().saturating_sub(size)];
+        for i in (size..remainder.len()).rev() {
+            quotient[i - size] = remainder[i];
+            let carried = remainder[i] * constant;
+            remainder[i - size] += carried;
+        }
+
+        if remainder.iter().take(size).any(|coefficient| !coefficient.is_zero()) {
+            return Err(PolynomialError::NonExactDivision);
+        }
+        Ok(Polynomials::from_coefficients(quotient))
+    }
+}
+
+impl<F: PrimeField> Evaluations<F> {
+    // Divides the polynomial of degree < m given by these evaluations over a coset of size m
+    // by the vanishing polynomial of `vanishing`, a domain of size n <= m, and interpolates the quotient.
+    // An exact quotient has degree < m - n, a higher one means the division left a remainder.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(size = self.domain().size(), vanishing = vanishing.size())))]
+    pub fn divide_by_vanishing(&self, vanishing: &Domain<F>) -> Result<Polynomials<F>, PolynomialError> {
+        let domain = self.domain();
+        if vanishing.size() > domain.size() {
+            return Err(PolynomialError::DomainError);
+        }
+
+        // Z over the evaluation domain repeats with period m / n
+        let period = domain.size() / vanishing.size();
+        let exponent = [vanishing.size() as u64];
+        let step = domain.generator().pow(exponent);
+        let constant = vanishing.vanishing_constant();
+        let mut current = domain.offset().pow(exponent);
+        let mut inverses = Vec::with_capacity(period);
+        for _ in 0..period {
+            inverses.push(current - constant);
+            current *= step;
+        }
+        // Fails when the two domains intersect
+        batch_inverse(&mut inverses)?;
+
+        let quotient: Vec<F> = self
+            .evaluations()
+            .iter()
+            .enumerate()
+            .map(|(i, value)| *value * inverses[i % period])
+            .collect();
+        let coefficients = domain.ifft(&quotient);
+
+        if coefficients[domain.size() - vanishing.size()..].iter().any(|coefficient| !coefficient.is_zero()) {
+            return Err(PolynomialError::NonExactDivision);
+        }
+        Ok(Polynomials::from_coefficients(coefficients))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fr;
+    use ark_ff::Zero;
+
+    use super::*;
+    use crate::univariate::{mocks::poly, types::PolynomialTrait};
+
+    #[test]
+    fn should_vanish_on_the_domain() {
+        let subgroup = Domain::<Fr>::new(8).unwrap();
+        let coset = subgroup.coset(Fr::from(5)).unwrap();
+
+        for domain in [subgroup, coset] {
+            let vanishing = domain.vanishing_polynomial();
+            assert_eq!(vanishing.degree(), Some(8));
+            for x in domain.elements() {
+                assert!(vanishing.evaluate(x).is_zero());
+                assert!(domain.evaluate_vanishing(x).is_zero());
+            }
+            assert_eq!(domain.evaluate_vanishing(Fr::from(3)), vanishing.evaluate(Fr::from(3)));
+            assert_eq!(Polynomials::from_roots(&domain.elements()), vanishing.to_dense());
+        }
+
+        // x^8 - 1
+        let mut expected = vec![0; 9];
+        expected[0] = -1;
+        expected[8] = 1;
+        assert_eq!(subgroup.vanishing_polynomial().to_dense(), poly(&expected));
+    }
+
+    #[test]
+    fn should_build_polynomials_from_roots() {
+        // (x - 1)(x - 2)(x + 3) = x^3 - 7x + 6
+        let roots = [Fr::from(1), Fr::from(2), Fr::from(-3)];
+        assert_eq!(Polynomials::from_roots(&roots), poly(&[6, -7, 0, 1]));
+
+        // (x - 2)^2
+        assert_eq!(Polynomials::from_roots(&[Fr::from(2), Fr::from(2)]), poly(&[4, -4, 1]));
+        assert_eq!(Polynomials::<Fr>::from_roots(&[]), poly(&[1]));
+    }
+
+    #[test]
+    fn should_divide_by_vanishing_polynomials() {
+        let domain = Domain::<Fr>::new(4).unwrap();
+        // 3x^3 + 5x^2 - 7x - 5
+        let quotient = poly(&[-5, -7, 5, 3]);
+        let dividend = &quotient * &domain.vanishing_polynomial().to_dense();
+
+        assert_eq!(dividend.divide_by_vanishing(&domain).unwrap(), quotient);
+        assert_eq!(Polynomials::zero().divide_by_vanishing(&domain).unwrap(), Polynomials::zero());
+
+        // Coset evaluations of size 8 hold polynomials of degree < 8
+        let coset = Domain::new_coset(8, Fr::from(7)).unwrap();
+        let evaluations = dividend.evaluate_over_domain(&coset);
+        assert_eq!(evaluations.divide_by_vanishing(&domain).unwrap(), quotient);
+
+        // The same over a larger coset, dividing by a coset vanishing polynomial
+        let shifted = domain.coset(Fr::from(3)).unwrap();
+        let dividend = &quotient * &shifted.vanishing_polynomial().to_dense();
+        let evaluations = dividend.evaluate_over_domain(&Domain::new_coset(16, Fr::from(7)).unwrap());
+        assert_eq!(dividend.divide_by_vanishing(&shifted).unwrap(), quotient);
+        assert_eq!(evaluations.divide_by_vanishing(&shifted).unwrap(), quotient);
+    }
+
+    #[test]
+    fn should_reject_inexact_division() {
+        let domain = Domain::<Fr>::new(4).unwrap();
+        let coset = Domain::new_coset(8, Fr::from(7)).unwrap();
+        // Off by one everywhere, so nonzero on the domain
+        let dividend = &poly(&[-5, -7, 5, 3]) * &domain.vanishing_polynomial().to_dense();
+        let unsatisfied = &dividend + &poly(&[1]);
+
+        assert_eq!(unsatisfied.divide_by_vanishing(&domain), Err(PolynomialError::NonExactDivision));
+        assert_eq!(
+            unsatisfied.evaluate_over_domain(&coset).divide_by_vanishing(&domain),
+            Err(PolynomialError::NonExactDivision)
+        );
+        // Nonzero polynomials of degree < n never vanish on the whole domain
+        assert_eq!(poly(&[0, 1]).divide_by_vanishing(&domain), Err(PolynomialError::NonExactDivision));
+
+        // The evaluation domain must be larger and disjoint from the vanishing domain
+        let subgroup = Domain::new(8).unwrap();
+        assert_eq!(
+            dividend.evaluate_over_domain(&subgroup).divide_by_vanishing(&domain),
+            Err(PolynomialError::DivisionByZero)
+        );
+        let small = Domain::new_coset(2, Fr::from(7)).unwrap();
+        assert_eq!(
+            dividend.evaluate_over_domain(&small).divide_by_vanishing(&domain),
+            Err(PolynomialError::DomainError)
+        );
+    }
+}
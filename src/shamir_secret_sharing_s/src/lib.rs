@@ -1,5 +1,4 @@
 use ark_ff::PrimeField;
-use ark_bn254::Fq;
 use polynomials::univariate::{types::PolynomialTrait, Polynomials};
 use rand::Rng;
 
@@ -16,17 +15,18 @@ impl ShamirSecret {
         }
     }
 
+    // Only the share count and threshold are traced, never the secret or the polynomial
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(total_shares = self.total_shares, threshold = self.threshold)))]
     pub fn generate_shares<F: PrimeField>(&self, secret_key: F) -> Vec<(F, F)> {
         // let's split the secret to 3:2 and place at coefficient 0 & 1 respectively
-        let ratio3 = F::from(secret_key) / F::from(3);
-        let mut coefficients = vec![ratio3, F::from(secret_key) - ratio3];
+        let ratio3 = secret_key / F::from(3);
+        let mut coefficients = vec![ratio3, secret_key - ratio3];
 
         for _ in 0..(self.threshold-1) {
             // generate a random number and push to the polynomial array
             let coeff = F::from(rand::rng().random::<u64>());
             coefficients.push(coeff);
         }
-        println!("Polynomial is {:?}", coefficients);
         let poly = Polynomials::new(coefficients).unwrap();
 
         let mut output_shares = vec![];
@@ -41,24 +41,32 @@ impl ShamirSecret {
         output_shares
     } 
 
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(shares = shares.len(), threshold = self.threshold)))]
     pub fn verify_secret<F: PrimeField>(&self, shares: Vec<(F, F)>, secret: F) -> bool {
         // length of shares must be at least self.threshold in length
         if shares.len() < self.threshold as usize {
-            println!("Not enough shares to verify");
+            #[cfg(feature = "tracing")]
+            tracing::debug!("not enough shares to verify");
             return false;
         }
 
-        // interpolate the shares to find the result
-        let polynomial = Polynomials::interpolate(shares);
+        // interpolate the shares to find the result, shares with a repeated x are rejected
+        let polynomial = match Polynomials::try_interpolate(shares) {
+            Ok(polynomial) => polynomial,
+            Err(_) => return false,
+        };
 
-        // Index 0 + 1 must equal secret
-        let derived_secret = polynomial.coefficients()[0] + polynomial.coefficients()[1];
+        // Index 0 + 1 must equal secret, trimmed polynomials may have fewer coefficients
+        let coefficient = |index: usize| polynomial.coefficients().get(index).copied().unwrap_or(F::zero());
+        let derived_secret = coefficient(0) + coefficient(1);
         derived_secret == secret
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use ark_bn254::Fq;
+
     use super::*;
 
     #[test]
@@ -71,5 +79,90 @@ mod tests {
 
         let is_valid = shamir.verify_secret(shares.clone(), secret_key);
         assert!(is_valid, "The secret should be valid");
+
+        // The same share twice cannot be interpolated
+        let duplicated = vec![shares[0], shares[0], shares[1]];
+        assert!(!shamir.verify_secret(duplicated, secret_key));
+    }
+
+    #[test]
+    fn should_verify_constant_shares() {
+        let shamir = ShamirSecret::new(3, 3);
+        // Shares with the same y interpolate to a constant, the zero polynomial has no coefficients
+        let constant = vec![(Fq::from(1), Fq::from(7)), (Fq::from(2), Fq::from(7)), (Fq::from(3), Fq::from(7))];
+        assert!(shamir.verify_secret(constant, Fq::from(7)));
+
+        let zero = vec![(Fq::from(1), Fq::from(0)), (Fq::from(2), Fq::from(0)), (Fq::from(3), Fq::from(0))];
+        assert!(shamir.verify_secret(zero.clone(), Fq::from(0)));
+        assert!(!shamir.verify_secret(zero, Fq::from(1)));
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn should_not_trace_the_secret() {
+        use std::{
+            fmt::Debug,
+            sync::{Arc, Mutex},
+        };
+
+        use tracing::{
+            field::{Field, Visit},
+            span::{Attributes, Id, Record},
+            Event, Metadata, Subscriber,
+        };
+
+        // Collects the name and every field value of all spans and events
+        #[derive(Clone, Default)]
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for Recorder {
+            fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+                self.0.lock().unwrap().push(format!("{}={:?}", field.name(), value));
+            }
+        }
+
+        impl Subscriber for Recorder {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                self.0.lock().unwrap().push(span.metadata().name().to_string());
+                span.record(&mut self.clone());
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _: &Id, values: &Record<'_>) {
+                values.record(&mut self.clone());
+            }
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+            fn event(&self, event: &Event<'_>) {
+                event.record(&mut self.clone());
+            }
+
+            fn enter(&self, _: &Id) {}
+
+            fn exit(&self, _: &Id) {}
+        }
+
+        let recorder = Recorder::default();
+        let shamir = ShamirSecret::new(5, 3);
+        let secret_key = Fq::from(123456789123u64);
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let shares = shamir.generate_shares(secret_key);
+            assert!(shamir.verify_secret(shares.clone(), secret_key));
+            assert!(!shamir.verify_secret(shares[..2].to_vec(), secret_key));
+        });
+
+        let records = recorder.0.lock().unwrap();
+        assert!(records.iter().any(|record| record == "generate_shares"));
+        assert!(records.iter().any(|record| record == "total_shares=5"));
+        for record in records.iter() {
+            assert!(!record.contains(&secret_key.to_string()), "{} leaks the secret", record);
+            assert!(!record.contains(&format!("{:?}", secret_key)), "{} leaks the secret", record);
+        }
     }
 }
\ No newline at end of file